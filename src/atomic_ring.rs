@@ -0,0 +1,224 @@
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Bounded single-producer single-consumer queue of `N` elements, lock-free thanks to atomic
+/// head and tail indices.
+///
+/// Unlike `Ring`, when full new elements are rejected instead of replacing the oldest one. Call
+/// `split` to obtain the `Producer` and the `Consumer` handles, which can live in different
+/// contexts, for example an interrupt handler and the main loop.
+#[derive(Debug)]
+pub struct AtomicRing<T, const N: usize> {
+    data: UnsafeCell<[MaybeUninit<T>; N]>,
+    /// Position of the next element to write, in `0..2N` to distinguish full from empty
+    head: AtomicUsize,
+    /// Position of the next element to read, in `0..2N` to distinguish full from empty
+    tail: AtomicUsize,
+}
+
+// SAFETY: the producer only writes slots not visible to the consumer and the consumer only reads
+// slots already published by the producer, with the `split` handles guaranteeing there is only
+// one of each
+unsafe impl<T: Send, const N: usize> Sync for AtomicRing<T, N> {}
+
+/// The write half of an `AtomicRing`
+#[derive(Debug)]
+pub struct Producer<'a, T, const N: usize> {
+    ring: &'a AtomicRing<T, N>,
+}
+
+/// The read half of an `AtomicRing`
+#[derive(Debug)]
+pub struct Consumer<'a, T, const N: usize> {
+    ring: &'a AtomicRing<T, N>,
+}
+
+impl<T: Copy, const N: usize> Default for AtomicRing<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy, const N: usize> AtomicRing<T, N> {
+    /// Creates a new empty `AtomicRing` of given size `N`, it's a `const fn` so that it can
+    /// initialize statically allocated storage, see `split`
+    pub const fn new() -> Self {
+        AtomicRing {
+            data: UnsafeCell::new([MaybeUninit::uninit(); N]),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// Splits the `AtomicRing` in the `Producer` and the `Consumer` handles, borrowing it for
+    /// their lifetime.
+    ///
+    /// To share them between an interrupt handler and the main loop, split a `&'static mut`
+    /// obtained soundly, for example from an RTIC local resource or a `static_cell::StaticCell`,
+    /// so that the handles are `'static` too. Avoid taking `&mut` of a `static mut`, which the
+    /// `static_mut_refs` lint rejects.
+    pub fn split(&mut self) -> (Producer<'_, T, N>, Consumer<'_, T, N>) {
+        (Producer { ring: self }, Consumer { ring: self })
+    }
+
+    /// Number of elements in the `AtomicRing`
+    pub fn len(&self) -> usize {
+        Self::distance(
+            self.tail.load(Ordering::Acquire),
+            self.head.load(Ordering::Acquire),
+        )
+    }
+
+    /// If the `AtomicRing` is empty. Zero elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// If the `AtomicRing` is full, `N` elements
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    /// Return the max size of the ring
+    pub fn size(&self) -> usize {
+        N
+    }
+
+    fn distance(tail: usize, head: usize) -> usize {
+        if N == 0 {
+            0
+        } else {
+            (head + 2 * N - tail) % (2 * N)
+        }
+    }
+
+    fn increment(index: usize) -> usize {
+        (index + 1) % (2 * N)
+    }
+}
+
+impl<T: Copy, const N: usize> Producer<'_, T, N> {
+    /// Append an element, returns it back in `Err` if the `AtomicRing` is full
+    pub fn push(&mut self, el: T) -> Result<(), T> {
+        let ring = self.ring;
+        let head = ring.head.load(Ordering::Relaxed);
+        let tail = ring.tail.load(Ordering::Acquire);
+        if AtomicRing::<T, N>::distance(tail, head) == N {
+            return Err(el);
+        }
+        // SAFETY: the slot at `head` is not readable by the consumer until `head` is published
+        unsafe {
+            (*ring.data.get())[head % N] = MaybeUninit::new(el);
+        }
+        ring.head
+            .store(AtomicRing::<T, N>::increment(head), Ordering::Release);
+        Ok(())
+    }
+
+    /// Number of elements in the `AtomicRing`
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// If the `AtomicRing` is empty. Zero elements
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+}
+
+impl<T: Copy, const N: usize> Consumer<'_, T, N> {
+    /// Removes and returns the oldest element, `None` if the `AtomicRing` is empty
+    pub fn pop(&mut self) -> Option<T> {
+        let ring = self.ring;
+        let tail = ring.tail.load(Ordering::Relaxed);
+        let head = ring.head.load(Ordering::Acquire);
+        if AtomicRing::<T, N>::distance(tail, head) == 0 {
+            return None;
+        }
+        // SAFETY: the slot at `tail` has been initialized and published by the producer, and it
+        // is not written again until `tail` is moved forward
+        let el = unsafe { (*ring.data.get())[tail % N].assume_init() };
+        ring.tail
+            .store(AtomicRing::<T, N>::increment(tail), Ordering::Release);
+        Some(el)
+    }
+
+    /// Number of elements in the `AtomicRing`
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// If the `AtomicRing` is empty. Zero elements
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AtomicRing;
+    use std::thread;
+
+    #[test]
+    pub fn test_atomic_ring() {
+        let mut ring: AtomicRing<u32, 3> = AtomicRing::new();
+        assert!(ring.is_empty());
+        assert_eq!(ring.size(), 3);
+        {
+            let (mut producer, mut consumer) = ring.split();
+            assert_eq!(consumer.pop(), None);
+            for round in 0..10 {
+                let base = round * 3;
+                assert_eq!(producer.push(base), Ok(()));
+                assert_eq!(producer.push(base + 1), Ok(()));
+                assert_eq!(producer.push(base + 2), Ok(()));
+                assert_eq!(producer.len(), 3);
+                assert_eq!(producer.push(42), Err(42));
+                assert_eq!(consumer.pop(), Some(base));
+                assert_eq!(producer.push(base + 3), Ok(()));
+                assert_eq!(consumer.pop(), Some(base + 1));
+                assert_eq!(consumer.pop(), Some(base + 2));
+                assert_eq!(consumer.pop(), Some(base + 3));
+                assert_eq!(consumer.pop(), None);
+                assert!(consumer.is_empty());
+            }
+            producer.push(7).unwrap();
+        }
+        assert_eq!(ring.len(), 1);
+        assert!(!ring.is_full());
+    }
+
+    #[test]
+    pub fn test_atomic_ring_threads() {
+        let ring: &'static mut AtomicRing<u32, 4> = Box::leak(Box::new(AtomicRing::new()));
+        let (mut producer, mut consumer) = ring.split();
+        let handle = thread::spawn(move || {
+            for i in 0..1000 {
+                while producer.push(i).is_err() {
+                    thread::yield_now();
+                }
+            }
+        });
+        let mut expected = 0;
+        while expected < 1000 {
+            match consumer.pop() {
+                Some(el) => {
+                    assert_eq!(el, expected);
+                    expected += 1;
+                }
+                None => thread::yield_now(),
+            }
+        }
+        handle.join().unwrap();
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    pub fn test_atomic_ring_zero_size() {
+        let mut ring: AtomicRing<u32, 0> = AtomicRing::new();
+        let (mut producer, mut consumer) = ring.split();
+        assert_eq!(producer.push(1), Err(1));
+        assert_eq!(consumer.pop(), None);
+    }
+}
@@ -1,6 +1,78 @@
 use crate::Ring;
+use core::cmp::Ordering;
 use core::ops::{Add, Div, Mul, Sub};
 
+/// Types which can be averaged, for example complex numbers represented as a struct
+pub trait Mean: Copy {
+    /// Returns the sum of `self` and `other`
+    fn add(self, other: Self) -> Self;
+
+    /// Returns `self` divided by the scalar `divisor`
+    fn div_scalar(self, divisor: f32) -> Self;
+}
+
+impl Mean for f32 {
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn div_scalar(self, divisor: f32) -> Self {
+        self / divisor
+    }
+}
+
+impl Mean for f64 {
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn div_scalar(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+/// Complex samples, like the in-phase and quadrature components of a signal
+pub trait Iq: Copy {
+    /// The real, in-phase, component
+    fn re(&self) -> f32;
+
+    /// The imaginary, quadrature, component
+    fn im(&self) -> f32;
+}
+
+/// The tuple is `(re, im)`
+impl Iq for (f32, f32) {
+    fn re(&self) -> f32 {
+        self.0
+    }
+
+    fn im(&self) -> f32 {
+        self.1
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<T: Iq, const N: usize> Ring<T, N> {
+    /// Returns a new `Ring` with the magnitude `sqrt(re^2 + im^2)` of every element, in the same
+    /// order
+    pub fn magnitude_ring(&self) -> Ring<f32, N> {
+        let mut result = Ring::new();
+        for el in self.iter() {
+            result.append(libm::hypotf(el.re(), el.im()));
+        }
+        result
+    }
+}
+
+impl<T: Mean, const N: usize> Ring<T, N> {
+    /// Calculate the average of the elements in the `Ring` using the `Mean` operations, unlike
+    /// `avg` it doesn't require the elements to be convertible in `f32`. `None` if empty.
+    pub fn mean(&self) -> Option<T> {
+        let sum = self.iter().reduce(Mean::add)?;
+        Some(sum.div_scalar(self.len() as f32))
+    }
+}
+
 impl<
         T: Copy
             + Default
@@ -36,3 +108,644 @@ impl<
         acc / len
     }
 }
+
+impl<T: Copy + Into<f32>, const N: usize> Ring<T, N> {
+    /// Number of finite elements in the `Ring`, `NaN` and infinite values excluded
+    pub fn count_finite(&self) -> usize {
+        self.iter().filter(|el| (*el).into().is_finite()).count()
+    }
+
+    /// Calculate the average of the finite elements in the `Ring`, skipping `NaN` and infinite
+    /// values
+    pub fn avg_finite(&self) -> f32 {
+        let mut acc = 0.0f32;
+        let mut count = 0u32;
+        for el in self
+            .iter()
+            .map(Into::into)
+            .filter(|el: &f32| el.is_finite())
+        {
+            acc += el;
+            count += 1;
+        }
+        acc / count as f32
+    }
+
+    /// Calculate the variance of the finite elements in the `Ring`, skipping `NaN` and infinite
+    /// values, use provided `avg` if `Some`, otherwise it calculates it with `avg_finite`.
+    pub fn var_finite(&self, avg: Option<f32>) -> f32 {
+        let avg = avg.unwrap_or_else(|| self.avg_finite());
+        let mut acc = 0.0f32;
+        let mut count = 0u32;
+        for el in self
+            .iter()
+            .map(Into::into)
+            .filter(|el: &f32| el.is_finite())
+        {
+            let val = el - avg;
+            acc += val * val;
+            count += 1;
+        }
+        acc / count as f32
+    }
+
+    /// Slope between the oldest and the newest element, `(newest - oldest) / (len() - 1)`,
+    /// `None` with less than 2 elements
+    pub fn endpoint_slope(&self) -> Option<f32> {
+        if self.len() < 2 {
+            return None;
+        }
+        let oldest: f32 = self.front()?.into();
+        let newest: f32 = self.last()?.into();
+        Some((newest - oldest) / (self.len() - 1) as f32)
+    }
+
+    /// Calculate the variance of the elements in the `Ring` in a single iteration using Welford's
+    /// online algorithm
+    pub fn var_single_pass(&self) -> f32 {
+        let mut count = 0u32;
+        let mut mean = 0.0f32;
+        let mut m2 = 0.0f32;
+        for el in self.iter().map(Into::<f32>::into) {
+            count += 1;
+            let delta = el - mean;
+            mean += delta / count as f32;
+            m2 += delta * (el - mean);
+        }
+        m2 / count as f32
+    }
+
+    /// Calculate the harmonic mean of the elements in the `Ring`, `len() / sum(1 / x)`.
+    ///
+    /// Returns `None` if the `Ring` is empty or any element is zero.
+    pub fn harmean(&self) -> Option<f32> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut acc = 0.0f32;
+        for el in self.iter().map(Into::<f32>::into) {
+            if el == 0.0 {
+                return None;
+            }
+            acc += 1.0 / el;
+        }
+        Some(self.len() as f32 / acc)
+    }
+
+    /// Difference between the average of the newest `split` elements and the average of the older
+    /// ones, a large value signals a level change. `None` if one of the two parts is empty.
+    pub fn mean_shift(&self, split: usize) -> Option<f32> {
+        if split == 0 || split >= self.len() {
+            return None;
+        }
+        let older_len = self.len() - split;
+        let (mut older, mut newer) = (0.0f32, 0.0f32);
+        for (i, el) in self.iter().map(Into::<f32>::into).enumerate() {
+            if i < older_len {
+                older += el;
+            } else {
+                newer += el;
+            }
+        }
+        Some(newer / split as f32 - older / older_len as f32)
+    }
+
+    /// Final high and low statistics of the CUSUM control chart run over the elements from the
+    /// oldest, where deviations from `target` smaller than `drift` are ignored. A high statistic
+    /// above a chosen limit signals a persistent increase, a low one a persistent decrease.
+    pub fn cusum(&self, target: f32, drift: f32) -> (f32, f32) {
+        self.iter()
+            .map(Into::<f32>::into)
+            .fold((0.0f32, 0.0f32), |(high, low), x| {
+                (
+                    (high + x - target - drift).max(0.0),
+                    (low + target - x - drift).max(0.0),
+                )
+            })
+    }
+
+    /// Normalized autocorrelation of the elements at `lag`, the mean is removed and the result is
+    /// divided by the energy, so that it's 1.0 at lag 0 and it shrinks as the overlap decreases.
+    ///
+    /// `None` if `lag` is not less than the length or if the elements are all equal.
+    pub fn autocorr(&self, lag: usize) -> Option<f32> {
+        if lag >= self.len() {
+            return None;
+        }
+        let (values, energy) = self.centered_energy()?;
+        Some(lag_product(&values[..self.len()], lag) / energy)
+    }
+
+    /// Estimates the period of the signal, in number of elements, as the lag in `1..len/2` of the
+    /// highest local maximum of the autocorrelation, see [`Ring::autocorr`].
+    ///
+    /// `None` if there is no peak, for example if the elements are all equal or monotonic.
+    pub fn dominant_period(&self) -> Option<usize> {
+        if self.len() < 2 {
+            return None;
+        }
+        let (values, energy) = self.centered_energy()?;
+        let values = &values[..self.len()];
+        let half = self.len() / 2;
+        let mut best: Option<(usize, f32)> = None;
+        let mut prev = 1.0f32;
+        let mut current = lag_product(values, 1) / energy;
+        for lag in 1..half {
+            let next = lag_product(values, lag + 1) / energy;
+            if current > prev && current >= next && best.is_none_or(|(_, r)| current > r) {
+                best = Some((lag, current));
+            }
+            prev = current;
+            current = next;
+        }
+        best.map(|(lag, _)| lag)
+    }
+
+    /// Elements converted from the oldest with the average subtracted and their energy, the sum
+    /// of their squares, `None` if it's 0
+    fn centered_energy(&self) -> Option<([f32; N], f32)> {
+        let (values, len) = self.centered();
+        let energy: f32 = values[..len].iter().map(|el| el * el).sum();
+        if energy == 0.0 {
+            None
+        } else {
+            Some((values, energy))
+        }
+    }
+
+    /// Elements converted from the oldest with the average subtracted, and their number
+    fn centered(&self) -> ([f32; N], usize) {
+        let mut values = [0.0f32; N];
+        for (value, el) in values.iter_mut().zip(self.iter()) {
+            *value = el.into();
+        }
+        let len = self.len();
+        let avg = values[..len].iter().sum::<f32>() / len as f32;
+        values[..len].iter_mut().for_each(|el| *el -= avg);
+        (values, len)
+    }
+
+    /// Returns the logical indices of the elements whose modified z-score, based on the median and
+    /// the median absolute deviation (MAD), is greater than `threshold` in absolute value. A
+    /// common `threshold` is `3.5`.
+    ///
+    /// If the MAD is 0, every element different from the median is an outlier.
+    pub fn outlier_indices(&self, threshold: f32) -> impl Iterator<Item = usize> + '_ {
+        let mut values = [0.0f32; N];
+        for (value, el) in values.iter_mut().zip(self.iter()) {
+            *value = el.into();
+        }
+        let values = &mut values[..self.len()];
+        let median = median_of(values);
+        values.iter_mut().for_each(|el| *el = (*el - median).abs());
+        let mad = median_of(values);
+        self.iter()
+            .enumerate()
+            .filter(move |(_, el)| (0.6745 * ((*el).into() - median) / mad).abs() > threshold)
+            .map(|(i, _)| i)
+    }
+}
+
+/// Sum of the products of the elements of `values` with the ones `lag` positions later
+fn lag_product(values: &[f32], lag: usize) -> f32 {
+    values
+        .iter()
+        .zip(values.iter().skip(lag))
+        .map(|(a, b)| a * b)
+        .sum()
+}
+
+/// Median of `values`, the average of the two central ones if their number is even, it sorts
+/// `values` in place
+fn median_of(values: &mut [f32]) -> f32 {
+    if values.is_empty() {
+        return f32::NAN;
+    }
+    values.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        values[mid]
+    } else {
+        (values[mid - 1] + values[mid]) / 2.0
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<T: Copy + Into<f32>, const N: usize> Ring<T, N> {
+    /// Calculate the normalized circular cross-correlation between this `Ring` and `other` at
+    /// every lag `k`, correlating the element at logical index `i` with the element of `other`
+    /// at logical index `i + k` (modulo the length).
+    ///
+    /// If the lengths are different only the newest `min(self.len(), other.len())` elements of
+    /// both are considered, lags not covered by the length are `0.0`. The result is `NaN` if the
+    /// elements of one of the rings are all equal.
+    pub fn xcorr(&self, other: &Ring<T, N>) -> [f32; N] {
+        let len = self.len().min(other.len());
+        let mut x = [0.0f32; N];
+        let mut y = [0.0f32; N];
+        for (i, el) in self.iter().skip(self.len() - len).enumerate() {
+            x[i] = el.into();
+        }
+        for (i, el) in other.iter().skip(other.len() - len).enumerate() {
+            y[i] = el.into();
+        }
+        let (x, y) = (&mut x[..len], &mut y[..len]);
+        let len_f = len as f32;
+        let x_avg = x.iter().sum::<f32>() / len_f;
+        let y_avg = y.iter().sum::<f32>() / len_f;
+        x.iter_mut().for_each(|el| *el -= x_avg);
+        y.iter_mut().for_each(|el| *el -= y_avg);
+        let x_energy: f32 = x.iter().map(|el| el * el).sum();
+        let y_energy: f32 = y.iter().map(|el| el * el).sum();
+        let norm = libm::sqrtf(x_energy * y_energy);
+
+        let mut result = [0.0f32; N];
+        for (lag, r) in result.iter_mut().take(len).enumerate() {
+            let mut acc = 0.0f32;
+            for i in 0..len {
+                acc += x[i] * y[(i + lag) % len];
+            }
+            *r = acc / norm;
+        }
+        result
+    }
+
+    /// Calculate the standard deviation of the elements in the `Ring` in a single iteration, see
+    /// [`Ring::var_single_pass`]
+    pub fn std_single_pass(&self) -> f32 {
+        libm::sqrtf(self.var_single_pass())
+    }
+
+    /// Calculate the average of the elements in the `Ring` weighting them by age, the weight of an
+    /// element halves every `half_life` elements of age, the newest element has weight `1.0`.
+    ///
+    /// Returns `None` if the `Ring` is empty or `half_life` is not positive.
+    pub fn decayed_avg(&self, half_life: f32) -> Option<f32> {
+        if self.is_empty() || half_life.is_nan() || half_life <= 0.0 {
+            return None;
+        }
+        let len = self.len();
+        let mut acc = 0.0f32;
+        let mut weights = 0.0f32;
+        for (i, el) in self.iter().enumerate() {
+            let age = (len - 1 - i) as f32;
+            let weight = libm::exp2f(-age / half_life);
+            acc += weight * el.into();
+            weights += weight;
+        }
+        Some(acc / weights)
+    }
+
+    /// Calculate the geometric mean of the elements in the `Ring`, `exp(avg(ln(x)))`.
+    ///
+    /// Returns `None` if the `Ring` is empty or any element is not strictly positive.
+    pub fn geomean(&self) -> Option<f32> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut acc = 0.0f32;
+        for el in self.iter().map(Into::<f32>::into) {
+            if el.is_nan() || el <= 0.0 {
+                return None;
+            }
+            acc += libm::logf(el);
+        }
+        Some(libm::expf(acc / self.len() as f32))
+    }
+
+    /// Calculate the skewness of the elements in the `Ring`, the third standardized moment
+    pub fn skewness(&self) -> f32 {
+        let (m2, m3, _) = self.central_moments();
+        m3 / (m2 * libm::sqrtf(m2))
+    }
+
+    /// Calculate the kurtosis of the elements in the `Ring`, the fourth standardized moment (it
+    /// is `3.0` for a normal distribution)
+    pub fn kurtosis(&self) -> f32 {
+        let (m2, _, m4) = self.central_moments();
+        m4 / (m2 * m2)
+    }
+
+    fn central_moments(&self) -> (f32, f32, f32) {
+        let len = self.len() as f32;
+        let avg = self.iter().map(Into::<f32>::into).sum::<f32>() / len;
+        let (mut m2, mut m3, mut m4) = (0.0f32, 0.0f32, 0.0f32);
+        for el in self.iter().map(Into::<f32>::into) {
+            let val = el - avg;
+            let val2 = val * val;
+            m2 += val2;
+            m3 += val2 * val;
+            m4 += val2 * val2;
+        }
+        (m2 / len, m3 / len, m4 / len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Mean;
+    use crate::Ring;
+    use core::f32::consts::PI;
+
+    #[test]
+    pub fn test_finite_stats() {
+        let mut circ: Ring<f32, 8> = Ring::new();
+        assert!(circ.avg_finite().is_nan());
+        circ.append(f32::NAN);
+        assert!(circ.avg_finite().is_nan());
+        for el in [1.0, f32::INFINITY, 2.0, 3.0, f32::NEG_INFINITY, 6.0].iter() {
+            circ.append(*el);
+        }
+        assert!(circ.avg().is_nan());
+        assert_eq!(circ.count_finite(), 4);
+        assert_eq!(circ.avg_finite(), 3.0);
+        assert_eq!(circ.var_finite(None), 3.5);
+        assert_eq!(circ.var_finite(Some(0.0)), 12.5);
+    }
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    struct Complex {
+        re: f32,
+        im: f32,
+    }
+
+    impl Mean for Complex {
+        fn add(self, other: Self) -> Self {
+            Complex {
+                re: self.re + other.re,
+                im: self.im + other.im,
+            }
+        }
+
+        fn div_scalar(self, divisor: f32) -> Self {
+            Complex {
+                re: self.re / divisor,
+                im: self.im / divisor,
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_mean() {
+        let mut circ: Ring<Complex, 4> = Ring::new();
+        assert_eq!(circ.mean(), None);
+        for (re, im) in [(9.0, 9.0), (1.0, 2.0), (3.0, -4.0), (-2.0, 0.5), (2.0, 1.5)].iter() {
+            circ.append(Complex { re: *re, im: *im });
+        }
+        assert_eq!(circ.mean(), Some(Complex { re: 1.0, im: 0.0 }));
+
+        let mut circ: Ring<f32, 4> = Ring::new();
+        circ.append(1.0);
+        circ.append(2.0);
+        assert_eq!(circ.mean(), Some(circ.avg()));
+    }
+
+    #[test]
+    pub fn test_endpoint_slope() {
+        let mut circ: Ring<i16, 4> = Ring::new();
+        assert_eq!(circ.endpoint_slope(), None);
+        circ.append(1);
+        assert_eq!(circ.endpoint_slope(), None);
+        for i in 0..10 {
+            circ.append(i * 3);
+        }
+        assert_eq!(circ.endpoint_slope(), Some(3.0));
+        for _ in 0..4 {
+            circ.append(-7);
+        }
+        assert_eq!(circ.endpoint_slope(), Some(0.0));
+    }
+
+    const DATASETS: [[f32; 6]; 4] = [
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        [-3.5, 0.25, 8.0, 8.0, -1.0, 2.5],
+        [1000.0, 1000.5, 999.5, 1000.0, 1001.0, 999.0],
+        [7.0, 7.0, 7.0, 7.0, 7.0, 7.0],
+    ];
+
+    #[test]
+    pub fn test_var_single_pass() {
+        let circ: Ring<f32, 8> = Ring::new();
+        assert!(circ.var_single_pass().is_nan());
+        for data in DATASETS.iter() {
+            let mut circ: Ring<f32, 4> = Ring::new();
+            for el in data.iter() {
+                circ.append(*el);
+            }
+            let two_pass = circ.var(None);
+            assert!((circ.var_single_pass() - two_pass).abs() <= 1e-3 * two_pass.max(1.0));
+        }
+    }
+
+    #[test]
+    pub fn test_harmean() {
+        let mut circ: Ring<u8, 4> = Ring::new();
+        assert_eq!(circ.harmean(), None);
+        for el in [1, 2, 4].iter() {
+            circ.append(*el);
+        }
+        assert!((circ.harmean().unwrap() - 12.0 / 7.0).abs() < 1e-6);
+        circ.append(0);
+        assert_eq!(circ.harmean(), None);
+    }
+
+    #[test]
+    pub fn test_mean_shift() {
+        let mut circ: Ring<i16, 8> = Ring::new();
+        assert_eq!(circ.mean_shift(1), None);
+        for el in [1, 0, 1, 0, 11, 10, 11, 10].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(circ.mean_shift(0), None);
+        assert_eq!(circ.mean_shift(8), None);
+        assert_eq!(circ.mean_shift(4), Some(10.0));
+        assert!(circ.mean_shift(2).unwrap() < 10.0);
+        assert!(circ.mean_shift(6).unwrap() < 10.0);
+    }
+
+    #[test]
+    pub fn test_cusum() {
+        let noise = [0.3, -0.2, 0.1, -0.4, 0.2, -0.1, 0.4, -0.3];
+        let mut circ: Ring<f32, 16> = Ring::new();
+        assert_eq!(circ.cusum(10.0, 0.5), (0.0, 0.0));
+        for el in noise.iter().chain(noise.iter()) {
+            circ.append(10.0 + el);
+        }
+        let (high, low) = circ.cusum(10.0, 0.5);
+        assert!(high < 1.0 && low < 1.0);
+
+        for el in noise.iter() {
+            circ.append(11.0 + el);
+        }
+        let (high, low) = circ.cusum(10.0, 0.5);
+        assert!(high > 3.0);
+        assert_eq!(low, 0.0);
+    }
+
+    #[test]
+    pub fn test_dominant_period() {
+        let mut circ: Ring<f32, 32> = Ring::new();
+        assert_eq!(circ.autocorr(0), None);
+        assert_eq!(circ.dominant_period(), None);
+        for _ in 0..32 {
+            circ.append(3.0);
+        }
+        assert_eq!(circ.autocorr(0), None);
+        assert_eq!(circ.dominant_period(), None);
+
+        for i in 0..40 {
+            circ.append((2.0 * PI * i as f32 / 5.0).sin());
+        }
+        assert!((circ.autocorr(0).unwrap() - 1.0).abs() < 1e-6);
+        assert!(circ.autocorr(5).unwrap() > 0.8);
+        assert!(circ.autocorr(2).unwrap() < 0.0);
+        assert_eq!(circ.autocorr(32), None);
+        assert_eq!(circ.dominant_period(), Some(5));
+
+        let mut circ: Ring<i16, 24> = Ring::new();
+        for _ in 0..3 {
+            for el in [0, 3, 6, 3, 0, -3, -6, -3].iter() {
+                circ.append(*el);
+            }
+        }
+        assert_eq!(circ.dominant_period(), Some(8));
+
+        let mut circ: Ring<i16, 24> = Ring::new();
+        for i in 0..24 {
+            circ.append(i);
+        }
+        assert_eq!(circ.dominant_period(), None);
+    }
+
+    #[test]
+    pub fn test_outlier_indices() {
+        let mut circ: Ring<f32, 8> = Ring::new();
+        assert_eq!(circ.outlier_indices(3.5).next(), None);
+        for el in [10.0, 10.5, 9.5, 10.2, 9.8, 10.1].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(circ.outlier_indices(3.5).next(), None);
+        circ.append(50.0);
+        circ.append(10.0);
+        assert_eq!(circ.outlier_indices(3.5).collect::<Vec<_>>(), vec![6]);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    pub fn test_std_single_pass() {
+        for data in DATASETS.iter() {
+            let mut circ: Ring<f32, 8> = Ring::new();
+            for el in data.iter() {
+                circ.append(*el);
+            }
+            let two_pass = libm::sqrtf(circ.var(None));
+            assert!((circ.std_single_pass() - two_pass).abs() <= 1e-3 * two_pass.max(1.0));
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    pub fn test_decayed_avg() {
+        let mut circ: Ring<f32, 8> = Ring::new();
+        assert_eq!(circ.decayed_avg(1.0), None);
+        circ.append(3.0);
+        assert_eq!(circ.decayed_avg(1.0), Some(3.0));
+        circ.append(6.0);
+        assert_eq!(circ.decayed_avg(0.0), None);
+        assert_eq!(circ.decayed_avg(1.0), Some(5.0));
+        for i in 0..8 {
+            circ.append(i as f32);
+        }
+        let mean = circ.avg();
+        let mut previous = circ.decayed_avg(0.5).unwrap();
+        assert!(previous > mean);
+        for half_life in [1.0, 4.0, 16.0, 1000.0].iter() {
+            let current = circ.decayed_avg(*half_life).unwrap();
+            assert!((current - mean).abs() < (previous - mean).abs());
+            previous = current;
+        }
+        assert!((previous - mean).abs() < 1e-2);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    pub fn test_geomean() {
+        let mut circ: Ring<u8, 4> = Ring::new();
+        assert_eq!(circ.geomean(), None);
+        for el in [1, 10, 100].iter() {
+            circ.append(*el);
+        }
+        assert!((circ.geomean().unwrap() - 10.0).abs() < 1e-4);
+        circ.append(0);
+        assert_eq!(circ.geomean(), None);
+
+        let mut circ: Ring<f32, 4> = Ring::new();
+        circ.append(2.0);
+        circ.append(-2.0);
+        assert_eq!(circ.geomean(), None);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    pub fn test_skewness_kurtosis() {
+        let mut circ: Ring<f32, 8> = Ring::new();
+        for el in [1.0, 2.0, 3.0, 4.0, 5.0].iter() {
+            circ.append(*el);
+        }
+        assert!(circ.skewness().abs() < 1e-6);
+        assert!((circ.kurtosis() - 1.7).abs() < 1e-5);
+
+        circ.clear();
+        for el in [1.0, 1.0, 1.0, 2.0, 2.0, 3.0, 10.0].iter() {
+            circ.append(*el);
+        }
+        assert!(circ.skewness() > 0.0);
+        assert!(circ.kurtosis() > 3.0);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    pub fn test_magnitude_ring() {
+        let mut circ: Ring<(f32, f32), 4> = Ring::new();
+        assert!(circ.magnitude_ring().is_empty());
+        for iq in [
+            (1.0, 1.0),
+            (3.0, 4.0),
+            (0.0, -2.0),
+            (-5.0, 12.0),
+            (-1.0, 0.0),
+        ]
+        .iter()
+        {
+            circ.append(*iq);
+        }
+        let magnitudes = circ.magnitude_ring();
+        assert_eq!(magnitudes.len(), 4);
+        assert_eq!(
+            magnitudes.iter().collect::<Vec<_>>(),
+            vec![5.0, 2.0, 13.0, 1.0]
+        );
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    pub fn test_xcorr() {
+        let mut x: Ring<u8, 8> = Ring::new();
+        let mut y: Ring<u8, 8> = Ring::new();
+        let shift = 3;
+        for i in 0..8u8 {
+            x.append(i);
+            y.append((i + 8 - shift) % 8);
+        }
+        let xcorr = x.xcorr(&y);
+        let peak = (0..8)
+            .max_by(|a, b| xcorr[*a].partial_cmp(&xcorr[*b]).unwrap())
+            .unwrap();
+        assert_eq!(peak, shift as usize);
+        assert!((xcorr[peak] - 1.0).abs() < 1e-5);
+
+        let auto = x.xcorr(&x);
+        assert!((auto[0] - 1.0).abs() < 1e-5);
+        assert!(auto.iter().all(|r| *r <= auto[0]));
+    }
+}
@@ -0,0 +1,227 @@
+use crate::Ring;
+use core::ops::{BitAnd, BitOr};
+
+impl<const N: usize> Ring<bool, N> {
+    /// Number of `true` elements, for example to compute the duty cycle of a digital input
+    pub fn count_true(&self) -> usize {
+        self.iter().filter(|el| *el).count()
+    }
+}
+
+/// Append only ring of `N` boolean flags packed one per bit, when full new flags replace the
+/// oldest ones, using an eighth of the memory of a `Ring<bool, N>`.
+///
+/// `B` is the number of bytes of the storage and must be at least `(N + 7) / 8`, for example
+/// `BitRing<10, 2>`. It's a separate parameter because an array length computed from `N` is not
+/// supported by stable Rust.
+#[derive(Debug, Clone, Copy)]
+pub struct BitRing<const N: usize, const B: usize> {
+    data: [u8; B],
+    next: usize,
+    len: usize,
+}
+
+/// Two rings are equal if they contain the same flags in the same order, regardless of their
+/// position in the backing bytes
+impl<const N: usize, const B: usize> PartialEq for BitRing<N, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<const N: usize, const B: usize> Eq for BitRing<N, B> {}
+
+impl<const N: usize, const B: usize> Default for BitRing<N, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, const B: usize> BitRing<N, B> {
+    /// Evaluated when `new` is instantiated, so that a storage too small for `N` bits is a
+    /// compile time error
+    const CHECK_SIZE: () = assert!(B * 8 >= N, "BitRing needs B >= (N + 7) / 8");
+
+    /// Creates a new empty `BitRing` of `N` flags, it doesn't compile if `B` bytes can't contain
+    /// `N` bits
+    ///
+    /// ```compile_fail
+    /// let bits: e_ring::BitRing<64, 1> = e_ring::BitRing::new();
+    /// ```
+    pub const fn new() -> Self {
+        let () = Self::CHECK_SIZE;
+        BitRing {
+            data: [0u8; B],
+            next: 0,
+            len: 0,
+        }
+    }
+
+    /// Append a flag to the `BitRing`, if there are already `N` flags, it replaces the oldest
+    pub fn append(&mut self, flag: bool) {
+        if N == 0 {
+            return;
+        }
+        let (byte, mask) = (self.next / 8, 1u8 << (self.next % 8));
+        if flag {
+            self.data[byte] |= mask;
+        } else {
+            self.data[byte] &= !mask;
+        }
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    /// Number of flags in the `BitRing`
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// If the `BitRing` is empty. Zero flags
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the max number of flags of the ring
+    pub fn size(&self) -> usize {
+        N
+    }
+
+    /// Return the flag at the `logical` position, where 0 is the oldest
+    pub fn get(&self, logical: usize) -> Option<bool> {
+        if logical < self.len {
+            let index = (self.next + N - self.len + logical) % N;
+            Some(self.data[index / 8] & (1 << (index % 8)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the flags starting from the oldest
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).filter_map(move |i| self.get(i))
+    }
+
+    /// Number of flags set, counted a byte at a time
+    pub fn count_ones(&self) -> usize {
+        self.data
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+
+    /// Removes all the flags
+    pub fn clear(&mut self) {
+        self.data = [0u8; B];
+        self.next = 0;
+        self.len = 0;
+    }
+}
+
+impl<T: Copy + BitAnd<Output = T>, const N: usize> Ring<T, N> {
+    /// Bitwise AND of all the elements, bits set are the flags always set in the `Ring`
+    pub fn bitand_all(&self) -> Option<T> {
+        self.iter().reduce(|acc, el| acc & el)
+    }
+}
+
+impl<T: Copy + BitOr<Output = T>, const N: usize> Ring<T, N> {
+    /// Bitwise OR of all the elements, bits set are the flags set at least once in the `Ring`
+    pub fn bitor_all(&self) -> Option<T> {
+        self.iter().reduce(|acc, el| acc | el)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BitRing, Ring};
+
+    #[test]
+    pub fn test_bitwise_reductions() {
+        let mut circ: Ring<u8, 4> = Ring::new();
+        assert_eq!(circ.bitand_all(), None);
+        assert_eq!(circ.bitor_all(), None);
+        for el in [
+            0b1111_0000,
+            0b0000_0001,
+            0b0000_0101,
+            0b1000_0011,
+            0b0001_0001,
+        ]
+        .iter()
+        {
+            circ.append(*el);
+        }
+        assert_eq!(circ.bitand_all(), Some(0b0000_0001));
+        assert_eq!(circ.bitor_all(), Some(0b1001_0111));
+
+        let mut flags: Ring<bool, 4> = Ring::new();
+        flags.append(false);
+        flags.append(true);
+        assert_eq!(flags.bitand_all(), Some(false));
+        assert_eq!(flags.bitor_all(), Some(true));
+    }
+
+    #[test]
+    pub fn test_count_true() {
+        let mut flags: Ring<bool, 6> = Ring::new();
+        assert_eq!(flags.count_true(), 0);
+        for el in [true, true, false, true, false, false, true, true].iter() {
+            flags.append(*el);
+        }
+        assert_eq!(flags.count_true(), 3);
+        flags.append(true);
+        assert_eq!(flags.count_true(), 4);
+    }
+
+    #[test]
+    pub fn test_bit_ring() {
+        let mut bits: BitRing<10, 2> = BitRing::new();
+        assert!(bits.is_empty());
+        assert_eq!(bits.size(), 10);
+        assert_eq!(bits.count_ones(), 0);
+        assert_eq!(bits.iter().next(), None);
+        let flags: Vec<bool> = (0..23).map(|i| i % 3 == 0 || i == 20).collect();
+        for (i, flag) in flags.iter().enumerate() {
+            bits.append(*flag);
+            let expected = &flags[(i + 1).saturating_sub(10)..=i];
+            assert_eq!(bits.len(), expected.len());
+            assert_eq!(bits.iter().collect::<Vec<_>>(), expected);
+            assert_eq!(bits.count_ones(), expected.iter().filter(|f| **f).count());
+        }
+        assert_eq!(bits.get(0), Some(false));
+        assert_eq!(bits.get(7), Some(true));
+        assert_eq!(bits.get(10), None);
+        assert!(core::mem::size_of::<BitRing<10, 2>>() < core::mem::size_of::<Ring<bool, 10>>());
+
+        bits.clear();
+        assert!(bits.is_empty());
+        assert_eq!(bits.count_ones(), 0);
+        bits.append(true);
+        assert_eq!(bits.iter().collect::<Vec<_>>(), vec![true]);
+
+        let mut empty: BitRing<0, 0> = BitRing::default();
+        empty.append(true);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    pub fn test_bit_ring_eq() {
+        let mut a: BitRing<5, 1> = BitRing::new();
+        let mut b: BitRing<5, 1> = BitRing::new();
+        for flag in [true, true, false, true, false, false, true].iter() {
+            a.append(*flag);
+        }
+        for flag in [false, true, false, false, true].iter() {
+            b.append(*flag);
+        }
+        assert_eq!(a, b);
+        b.append(true);
+        assert_ne!(a, b);
+        a.append(true);
+        assert_eq!(a, b);
+        a.clear();
+        assert_ne!(a, b);
+        assert_eq!(a, BitRing::new());
+    }
+}
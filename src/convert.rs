@@ -0,0 +1,112 @@
+use crate::Ring;
+use core::convert::TryFrom;
+
+/// Types having a minimum and a maximum value
+pub trait Bounded {
+    /// The minimum value
+    const MIN: Self;
+    /// The maximum value
+    const MAX: Self;
+}
+
+macro_rules! impl_bounded {
+    ($($t:ty),*) => {
+        $(
+            impl Bounded for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+            }
+        )*
+    };
+}
+
+impl_bounded!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Conversion from `f64`, saturating at the type bounds and truncating the fractional part
+/// toward zero for integers, like `as` casts do
+pub trait FromF64 {
+    /// Converts `value` into `Self`
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_from_f64 {
+    ($($t:ty),*) => {
+        $(
+            impl FromF64 for $t {
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_from_f64!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Types having a zero value usable in const contexts
+pub trait Zero {
+    /// The zero value
+    const ZERO: Self;
+}
+
+macro_rules! impl_zero {
+    ($zero:expr => $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                const ZERO: Self = $zero;
+            }
+        )*
+    };
+}
+
+impl_zero!(0 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_zero!(0.0 => f32, f64);
+impl_zero!(false => bool);
+
+impl<T: Copy + PartialOrd, const N: usize> Ring<T, N> {
+    /// Returns a new `Ring` with every element converted to `U`, elements not fitting in `U` are
+    /// saturated to `U::MIN` or `U::MAX` instead of wrapping around like `as` casts do
+    pub fn convert_saturating<U>(&self) -> Ring<U, N>
+    where
+        U: Copy + Default + Bounded + TryFrom<T>,
+        T: TryFrom<U>,
+    {
+        let mut result = Ring::new();
+        for el in self.iter() {
+            let converted = U::try_from(el).unwrap_or_else(|_| match T::try_from(U::MIN) {
+                Ok(min) if el < min => U::MIN,
+                _ => U::MAX,
+            });
+            result.append(converted);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Ring;
+
+    #[test]
+    pub fn test_convert_saturating() {
+        let mut circ: Ring<i16, 4> = Ring::new();
+        let converted: Ring<u8, 4> = circ.convert_saturating();
+        assert!(converted.is_empty());
+        for el in [-300i16, -1, 100, 300].iter() {
+            circ.append(*el);
+        }
+        let converted: Ring<u8, 4> = circ.convert_saturating();
+        assert_eq!(converted.iter().collect::<Vec<_>>(), vec![0, 0, 100, 255]);
+        let converted: Ring<i8, 4> = circ.convert_saturating();
+        assert_eq!(
+            converted.iter().collect::<Vec<_>>(),
+            vec![-128, -1, 100, 127]
+        );
+
+        let mut circ: Ring<u16, 2> = Ring::new();
+        circ.append(7);
+        circ.append(40_000);
+        let converted: Ring<i16, 2> = circ.convert_saturating();
+        assert_eq!(converted.iter().collect::<Vec<_>>(), vec![7, i16::MAX]);
+    }
+}
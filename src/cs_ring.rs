@@ -0,0 +1,72 @@
+use crate::{Ring, Zero};
+use core::cell::RefCell;
+use critical_section::{CriticalSection, Mutex};
+
+/// A `Ring` which can be shared between interrupt handlers and the main loop, every access
+/// requires a critical section
+#[derive(Debug)]
+pub struct CsRing<T, const N: usize> {
+    inner: Mutex<RefCell<Ring<T, N>>>,
+}
+
+impl<T: Copy + Zero, const N: usize> CsRing<T, N> {
+    /// Creates a new empty `CsRing` of given size `N`, it's a `const fn` so that the ring can be a
+    /// `static` shared by interrupt handlers and the main loop
+    pub const fn new() -> Self {
+        CsRing {
+            inner: Mutex::new(RefCell::new(Ring::zeroed())),
+        }
+    }
+}
+
+impl<T: Copy + Zero, const N: usize> Default for CsRing<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy, const N: usize> CsRing<T, N> {
+    /// Append an element to the `Ring`, if there are already `N` elements, it replaces the oldest.
+    pub fn append(&self, cs: CriticalSection<'_>, el: T) {
+        self.inner.borrow_ref_mut(cs).append(el);
+    }
+
+    /// Returns a copy of the `Ring`, to be processed outside the critical section
+    pub fn snapshot(&self, cs: CriticalSection<'_>) -> Ring<T, N> {
+        self.inner.borrow_ref(cs).snapshot()
+    }
+
+    /// Resets the `Ring`
+    pub fn clear(&self, cs: CriticalSection<'_>) {
+        self.inner.borrow_ref_mut(cs).clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CsRing;
+    use std::thread;
+
+    static RING: CsRing<u32, 16> = CsRing::new();
+
+    #[test]
+    pub fn test_cs_ring() {
+        let producer = thread::spawn(|| {
+            for i in 0..1000 {
+                critical_section::with(|cs| RING.append(cs, i));
+            }
+        });
+        for _ in 0..100 {
+            let snapshot = critical_section::with(|cs| RING.snapshot(cs));
+            // a snapshot is always consistent, elements are consecutive
+            let elements: Vec<u32> = snapshot.iter().collect();
+            assert!(elements.windows(2).all(|w| w[1] == w[0] + 1));
+        }
+        producer.join().unwrap();
+        let snapshot = critical_section::with(|cs| RING.snapshot(cs));
+        assert_eq!(snapshot.len(), 16);
+        assert_eq!(snapshot.last(), Some(999));
+        critical_section::with(|cs| RING.clear(cs));
+        assert!(critical_section::with(|cs| RING.snapshot(cs)).is_empty());
+    }
+}
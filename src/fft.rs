@@ -0,0 +1,90 @@
+use crate::Ring;
+use core::convert::TryInto;
+use microfft::complex::*;
+use microfft::Complex32;
+
+impl<T: Copy + Into<f32>, const N: usize> Ring<T, N> {
+    /// Calculate the magnitude spectrum of the elements in the `Ring` with an `M` points FFT.
+    ///
+    /// If the `Ring` contains less than `M` elements they are zero-padded, if it contains more
+    /// only the newest `M` elements are considered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is not a power of two between 2 and 4096.
+    pub fn fft_magnitude<const M: usize>(&self) -> [f32; M] {
+        let mut buffer = [Complex32::new(0.0, 0.0); M];
+        let skip = self.len().saturating_sub(M);
+        for (c, el) in buffer.iter_mut().zip(self.iter().skip(skip)) {
+            c.re = el.into();
+        }
+        cfft(&mut buffer);
+        let mut result = [0.0f32; M];
+        for (r, c) in result.iter_mut().zip(buffer.iter()) {
+            *r = libm::sqrtf(c.re * c.re + c.im * c.im);
+        }
+        result
+    }
+}
+
+/// In place FFT of `buffer`, dispatching to the `microfft` function of the right size
+fn cfft(buffer: &mut [Complex32]) {
+    macro_rules! dispatch {
+        ($($n:expr => $f:ident,)*) => {
+            match buffer.len() {
+                $($n => {
+                    let _ = $f(buffer.try_into().unwrap());
+                })*
+                _ => panic!("FFT size must be a power of two between 2 and 4096"),
+            }
+        };
+    }
+    dispatch! {
+        2 => cfft_2,
+        4 => cfft_4,
+        8 => cfft_8,
+        16 => cfft_16,
+        32 => cfft_32,
+        64 => cfft_64,
+        128 => cfft_128,
+        256 => cfft_256,
+        512 => cfft_512,
+        1024 => cfft_1024,
+        2048 => cfft_2048,
+        4096 => cfft_4096,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Ring;
+    use core::f32::consts::PI;
+
+    #[test]
+    pub fn test_fft_magnitude() {
+        let mut circ: Ring<f32, 20> = Ring::new();
+        assert_eq!(circ.fft_magnitude::<8>(), [0.0; 8]);
+        for _ in 0..4 {
+            circ.append(100.0);
+        }
+        // only the newest 16 elements are considered
+        for i in 0..16 {
+            circ.append((2.0 * PI * 3.0 * i as f32 / 16.0).sin());
+        }
+        let spectrum = circ.fft_magnitude::<16>();
+        let peak = (0..8)
+            .max_by(|a, b| spectrum[*a].partial_cmp(&spectrum[*b]).unwrap())
+            .unwrap();
+        assert_eq!(peak, 3);
+        assert!((spectrum[3] - 8.0).abs() < 1e-3);
+        assert!((spectrum[13] - 8.0).abs() < 1e-3);
+        assert!(spectrum[0] < 1e-3);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_fft_magnitude_wrong_size() {
+        let circ: Ring<f32, 20> = Ring::new();
+        circ.fft_magnitude::<12>();
+    }
+}
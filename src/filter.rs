@@ -0,0 +1,328 @@
+use crate::Ring;
+use core::cmp::Ordering;
+use core::f32::consts::PI;
+
+impl<T: Copy + Default + PartialOrd, const N: usize> Ring<T, N> {
+    /// Returns a new `Ring` where every element is the median of the `W` elements ending at the
+    /// same position, useful to remove spikes.
+    ///
+    /// The first `W - 1` positions use the smaller window available, with an even number of
+    /// elements the lower median is taken.
+    pub fn median_filtered<const W: usize>(&self) -> Ring<T, N> {
+        self.rolling_apply::<W, _>(|window| {
+            let mut sorted = [T::default(); W];
+            let sorted = &mut sorted[..window.len()];
+            sorted.copy_from_slice(window);
+            sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            sorted[(window.len() - 1) / 2]
+        })
+    }
+}
+
+impl<T: Copy + Default, const N: usize> Ring<T, N> {
+    /// Returns a new `Ring` where every element is the result of `f` applied to the window of
+    /// the `W` elements ending at the same position, from the oldest to the newest.
+    ///
+    /// The first `W - 1` positions use the smaller window available, so the slice passed to `f`
+    /// is never empty. If `W` is 0 a copy of the `Ring` is returned.
+    pub fn rolling_apply<const W: usize, F: FnMut(&[T]) -> T>(&self, mut f: F) -> Ring<T, N> {
+        let mut result = Ring::new();
+        let mut window = [T::default(); W];
+        for (i, el) in self.iter().enumerate() {
+            if W == 0 {
+                result.append(el);
+                continue;
+            }
+            let count = (i + 1).min(W);
+            if i >= W {
+                window.copy_within(1.., 0);
+            }
+            window[count - 1] = el;
+            result.append(f(&window[..count]));
+        }
+        result
+    }
+}
+
+impl<T: Copy + Into<f32>, const N: usize> Ring<T, N> {
+    /// Output at the newest element of a first-order IIR low-pass filter with cutoff frequency
+    /// `cutoff_hz`, run over the `Ring` from the oldest element sampled at `sample_hz`.
+    ///
+    /// Returns `None` if the `Ring` is empty or the frequencies are not positive.
+    pub fn lowpass(&self, cutoff_hz: f32, sample_hz: f32) -> Option<f32> {
+        let (rc, dt) = rc_dt(cutoff_hz, sample_hz)?;
+        let alpha = dt / (rc + dt);
+        let mut iter = self.iter().map(Into::<f32>::into);
+        let first = iter.next()?;
+        Some(iter.fold(first, |y, x| y + alpha * (x - y)))
+    }
+
+    /// Output at the newest element of a first-order IIR high-pass filter with cutoff frequency
+    /// `cutoff_hz`, run over the `Ring` from the oldest element sampled at `sample_hz`. It's the
+    /// fast changing component, near 0 for a constant or slowly drifting signal.
+    ///
+    /// Returns `None` if the `Ring` is empty or the frequencies are not positive.
+    pub fn highpass(&self, cutoff_hz: f32, sample_hz: f32) -> Option<f32> {
+        let (rc, dt) = rc_dt(cutoff_hz, sample_hz)?;
+        let alpha = rc / (rc + dt);
+        let mut iter = self.iter().map(Into::<f32>::into);
+        let first = iter.next()?;
+        let (y, _) = iter.fold((0.0, first), |(y, prev), x| (alpha * (y + x - prev), x));
+        Some(y)
+    }
+
+    /// Returns an iterator over the elements, angles in radians, starting from the oldest with
+    /// the jumps greater than `π` between consecutive elements removed by adding multiples of
+    /// `2π`, so that the phase is continuous. Non-finite elements are yielded unchanged and
+    /// don't affect the following ones.
+    pub fn unwrap_phase_iter(&self) -> impl Iterator<Item = f32> + '_ {
+        self.iter()
+            .map(Into::<f32>::into)
+            .scan(None, |state: &mut Option<(f32, f32)>, x| {
+                if !x.is_finite() {
+                    return Some(x);
+                }
+                let offset = match *state {
+                    None => 0.0,
+                    Some((prev, offset)) => offset - round((x - prev) / (2.0 * PI)) * 2.0 * PI,
+                };
+                *state = Some((x, offset));
+                Some(x + offset)
+            })
+    }
+}
+
+impl<T: Copy + PartialEq + Into<f32>, const N: usize> Ring<T, N> {
+    /// Returns a new `Ring` where the elements equal to `sentinel`, marking missing readings, are
+    /// replaced by linear interpolation between the valid elements around them. Missing elements
+    /// before the first or after the last valid one hold the nearest valid value.
+    ///
+    /// If no element is valid, every element of the result is `NaN`.
+    pub fn interpolate_sentinel(&self, sentinel: T) -> Ring<f32, N> {
+        let mut values = [f32::NAN; N];
+        let mut prev: Option<usize> = None;
+        for (i, el) in self.iter().enumerate() {
+            if el == sentinel {
+                continue;
+            }
+            let value = el.into();
+            values[i] = value;
+            match prev {
+                Some(p) => {
+                    let start = values[p];
+                    for (k, missing) in values.iter_mut().enumerate().take(i).skip(p + 1) {
+                        *missing = start + (value - start) * (k - p) as f32 / (i - p) as f32;
+                    }
+                }
+                None => values[..i].iter_mut().for_each(|missing| *missing = value),
+            }
+            prev = Some(i);
+        }
+        if let Some(p) = prev {
+            let last = values[p];
+            values[p + 1..self.len()]
+                .iter_mut()
+                .for_each(|missing| *missing = last);
+        }
+        let mut result = Ring::new();
+        for value in values[..self.len()].iter() {
+            result.append(*value);
+        }
+        result
+    }
+}
+
+/// Rounds `x` to the nearest integer, halfway cases away from zero, `x` must be finite
+fn round(x: f32) -> f32 {
+    // from 2^23 on every f32 is an integer
+    if x.abs() >= 8_388_608.0 {
+        x
+    } else if x < 0.0 {
+        (x - 0.5) as i32 as f32
+    } else {
+        (x + 0.5) as i32 as f32
+    }
+}
+
+/// Time constant `RC` of a first-order filter with cutoff `cutoff_hz` and the sampling period
+fn rc_dt(cutoff_hz: f32, sample_hz: f32) -> Option<(f32, f32)> {
+    if cutoff_hz > 0.0 && sample_hz > 0.0 {
+        Some((1.0 / (2.0 * PI * cutoff_hz), 1.0 / sample_hz))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Ring;
+    use core::f32::consts::PI;
+
+    #[test]
+    pub fn test_median_filtered() {
+        let mut circ: Ring<i32, 8> = Ring::new();
+        assert!(circ.median_filtered::<3>().is_empty());
+        for el in [5, 6, 7, 100, 8, 9, 10, 11, 12].iter() {
+            circ.append(*el);
+        }
+        let filtered = circ.median_filtered::<3>();
+        assert_eq!(filtered.len(), circ.len());
+        assert_eq!(
+            filtered.iter().collect::<Vec<_>>(),
+            vec![6, 6, 7, 8, 9, 9, 10, 11]
+        );
+        assert!(filtered.iter().all(|el| el < 100));
+
+        let filtered = circ.median_filtered::<1>();
+        assert_eq!(filtered, circ);
+    }
+
+    #[test]
+    pub fn test_rolling_apply() {
+        let mut circ: Ring<i32, 8> = Ring::new();
+        let max_filter = |w: &[i32]| *w.iter().max().unwrap();
+        assert!(circ.rolling_apply::<3, _>(max_filter).is_empty());
+        for el in [9, 1, 2, 3, 7, 1, 1, 1, 1, 0].iter() {
+            circ.append(*el);
+        }
+        let filtered = circ.rolling_apply::<3, _>(max_filter);
+        assert_eq!(
+            filtered.iter().collect::<Vec<_>>(),
+            vec![2, 3, 7, 7, 7, 1, 1, 1]
+        );
+        let windows = circ.rolling_apply::<3, _>(|w| w.len() as i32);
+        assert_eq!(
+            windows.iter().collect::<Vec<_>>(),
+            vec![1, 2, 3, 3, 3, 3, 3, 3]
+        );
+        assert_eq!(circ.rolling_apply::<0, _>(max_filter), circ);
+
+        let mut calls = 0;
+        let counted = circ.rolling_apply::<2, _>(|w| {
+            calls += 1;
+            w[0] + calls
+        });
+        assert_eq!(calls, 8);
+        assert_eq!(
+            counted.iter().collect::<Vec<_>>(),
+            vec![3, 4, 6, 11, 6, 7, 8, 9]
+        );
+    }
+
+    #[test]
+    pub fn test_lowpass() {
+        let mut circ: Ring<f32, 16> = Ring::new();
+        assert_eq!(circ.lowpass(1.0, 100.0), None);
+        circ.append(2.0);
+        assert_eq!(circ.lowpass(1.0, 100.0), Some(2.0));
+        assert_eq!(circ.lowpass(0.0, 100.0), None);
+        assert_eq!(circ.lowpass(1.0, -1.0), None);
+        for i in 0..16 {
+            circ.append(if i < 8 { 0.0 } else { 10.0 });
+        }
+        let slow = circ.lowpass(1.0, 100.0).unwrap();
+        let fast = circ.lowpass(20.0, 100.0).unwrap();
+        assert!(0.0 < slow && slow < fast && fast < 10.0);
+    }
+
+    #[test]
+    pub fn test_highpass() {
+        let mut circ: Ring<f32, 16> = Ring::new();
+        assert_eq!(circ.highpass(1.0, 100.0), None);
+        assert_eq!(circ.highpass(-1.0, 100.0), None);
+        for _ in 0..16 {
+            circ.append(5.0);
+        }
+        assert!(circ.highpass(1.0, 100.0).unwrap().abs() < 1e-6);
+        circ.append(10.0);
+        let transient = circ.highpass(1.0, 100.0).unwrap();
+        assert!(transient > 4.0);
+        for _ in 0..4 {
+            circ.append(10.0);
+        }
+        let decayed = circ.highpass(1.0, 100.0).unwrap();
+        assert!(0.0 < decayed && decayed < transient);
+    }
+
+    #[test]
+    pub fn test_unwrap_phase_iter() {
+        let mut circ: Ring<f32, 16> = Ring::new();
+        assert_eq!(circ.unwrap_phase_iter().next(), None);
+        let mut phase = 2.0f32;
+        for _ in 0..16 {
+            circ.append(phase);
+            phase += 2.5;
+            if phase > PI {
+                phase -= 2.0 * PI;
+            }
+        }
+        assert!(circ.iter().any(|el| el < 0.0));
+        let unwrapped: Vec<f32> = circ.unwrap_phase_iter().collect();
+        assert_eq!(unwrapped[0], 2.0);
+        for w in unwrapped.windows(2) {
+            assert!((w[1] - w[0] - 2.5).abs() < 1e-3);
+        }
+
+        circ.clear();
+        for el in [3.0, -3.0, 3.0].iter() {
+            circ.append(*el);
+        }
+        let unwrapped: Vec<f32> = circ.unwrap_phase_iter().collect();
+        assert!((unwrapped[1] - (2.0 * PI - 3.0)).abs() < 1e-5);
+        assert!((unwrapped[2] - 3.0).abs() < 1e-5);
+
+        circ.clear();
+        for el in [0.0, 1000.0, f32::INFINITY, 1001.0, f32::NAN, 1001.5].iter() {
+            circ.append(*el);
+        }
+        let unwrapped: Vec<f32> = circ.unwrap_phase_iter().collect();
+        assert_eq!(unwrapped[0], 0.0);
+        assert!(unwrapped[1].abs() <= PI);
+        assert_eq!(unwrapped[2], f32::INFINITY);
+        assert!((unwrapped[3] - unwrapped[1] - 1.0).abs() < 1e-3);
+        assert!(unwrapped[4].is_nan());
+        assert!((unwrapped[5] - unwrapped[3] - 0.5).abs() < 1e-3);
+
+        circ.clear();
+        for el in [0.0, 1e9, -1e30, f32::NEG_INFINITY].iter() {
+            circ.append(*el);
+        }
+        let unwrapped: Vec<f32> = circ.unwrap_phase_iter().collect();
+        assert!(unwrapped[..3].iter().all(|el| el.is_finite()));
+        assert_eq!(unwrapped[3], f32::NEG_INFINITY);
+    }
+
+    #[test]
+    pub fn test_interpolate_sentinel() {
+        let mut circ: Ring<i16, 10> = Ring::new();
+        assert!(circ.interpolate_sentinel(i16::MIN).is_empty());
+        for el in [
+            i16::MIN,
+            i16::MIN,
+            4,
+            i16::MIN,
+            8,
+            2,
+            i16::MIN,
+            i16::MIN,
+            -4,
+            i16::MIN,
+        ]
+        .iter()
+        {
+            circ.append(*el);
+        }
+        let interpolated = circ.interpolate_sentinel(i16::MIN);
+        assert_eq!(
+            interpolated.iter().collect::<Vec<_>>(),
+            vec![4.0, 4.0, 4.0, 6.0, 8.0, 2.0, 0.0, -2.0, -4.0, -4.0]
+        );
+
+        let mut circ: Ring<i16, 4> = Ring::new();
+        circ.append(i16::MIN);
+        circ.append(i16::MIN);
+        let interpolated = circ.interpolate_sentinel(i16::MIN);
+        assert_eq!(interpolated.len(), 2);
+        assert!(interpolated.iter().all(|el| el.is_nan()));
+    }
+}
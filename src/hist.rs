@@ -3,10 +3,20 @@
 //! This module provides implementation to draw histograms on a Display
 //!
 
+use crate::rescale::RescaleIterator;
+use crate::ring::RingIterator;
 use crate::{FindRange, Range, Ring};
+use core::fmt::Write;
 use embedded_graphics::geometry::{Point, Size};
+use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::{
+    Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, GrayColor, Rgb555, Rgb565,
+    Rgb666, Rgb888, RgbColor,
+};
 use embedded_graphics::prelude::{DrawTarget, PixelColor, Primitive};
-use embedded_graphics::primitives::{Line, PrimitiveStyle};
+use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Baseline, Text};
 use embedded_graphics::Drawable;
 
 /// Represent a histogram with values contained in the `ring` but rescaled to fit in the window
@@ -15,15 +25,156 @@ use embedded_graphics::Drawable;
 pub struct Hist {
     upper_left: Point,
     size: Size,
+    bar_width: u32,
+    gap: u32,
 }
 
 /// A struct containing three points
 pub type ThreePoints = [Point; 3];
 
+/// Colors which can be linearly interpolated, used by `Hist::draw_gradient`
+pub trait Interpolate: PixelColor {
+    /// Returns the color at `t` between `low`, when `t` is 0, and `high`, when `t` is 1
+    fn interpolate(low: Self, high: Self, t: f32) -> Self;
+}
+
+fn lerp(low: u8, high: u8, t: f32) -> u8 {
+    (low as f32 + (high as f32 - low as f32) * t + 0.5) as u8
+}
+
+macro_rules! impl_interpolate_rgb {
+    ($($t:ty),*) => {
+        $(
+            impl Interpolate for $t {
+                fn interpolate(low: Self, high: Self, t: f32) -> Self {
+                    <$t>::new(
+                        lerp(low.r(), high.r(), t),
+                        lerp(low.g(), high.g(), t),
+                        lerp(low.b(), high.b(), t),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_rgb!(Rgb555, Rgb565, Rgb666, Rgb888, Bgr555, Bgr565, Bgr666, Bgr888);
+
+macro_rules! impl_interpolate_gray {
+    ($($t:ty),*) => {
+        $(
+            impl Interpolate for $t {
+                fn interpolate(low: Self, high: Self, t: f32) -> Self {
+                    <$t>::new(lerp(low.luma(), high.luma(), t))
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_gray!(Gray2, Gray4, Gray8);
+
+/// The nearest of `low` and `high`
+impl Interpolate for BinaryColor {
+    fn interpolate(low: Self, high: Self, t: f32) -> Self {
+        if t < 0.5 {
+            low
+        } else {
+            high
+        }
+    }
+}
+
+/// Stack buffer to format an `i16` without allocating
+#[derive(Default)]
+struct LabelBuf {
+    buf: [u8; 6],
+    len: usize,
+}
+
+impl LabelBuf {
+    fn format(value: i16) -> LabelBuf {
+        let mut label = LabelBuf::default();
+        // "-32768" is the longest value and fits the buffer
+        let _ = write!(label, "{}", value);
+        label
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+}
+
+impl Write for LabelBuf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(core::fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// A data source of at most `N` values which can be drawn by `Hist`, `Ring` is the primary
+/// implementation
+pub trait HistData<const N: usize>: FindRange<i16> {
+    /// The iterator returned by `iter`
+    type Iter<'a>: Iterator<Item = i16>
+    where
+        Self: 'a;
+
+    /// Number of values in the data source
+    fn len(&self) -> usize;
+
+    /// If the data source doesn't contain any value
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of values in the data source
+    fn size(&self) -> usize;
+
+    /// Returns an iterator over the values starting from the oldest
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+impl<const N: usize> HistData<N> for Ring<i16, N> {
+    type Iter<'a> = RingIterator<'a, i16, N>;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn size(&self) -> usize {
+        self.size()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+}
+
+/// Displays able to move part of their content, for example with a hardware scroll or a blit,
+/// used by `Hist::draw_scrolling`
+pub trait Scroll {
+    /// Moves the content of `area` to the left by `pixels`, the pixels on the right are left
+    /// undefined. Returns `false` if it's not supported, for example for the given `area`.
+    fn scroll_left(&mut self, area: &Rectangle, pixels: u32) -> bool;
+}
+
+/// State kept between calls of `Hist::draw_scrolling`
+#[derive(Debug, Default)]
+pub struct ScrollState {
+    range: Option<Range<i16>>,
+}
+
 /// Errors in creating the histogram
 #[derive(Debug)]
 pub enum Error {
-    /// The ring size must match the width (distance over the x axis) of the given points
+    /// The ring size multiplied by the bar width plus gap must match the width (distance over the
+    /// x axis) of the given points
     RingSizeMismatch {
         /// The hist window width
         width: u32,
@@ -32,12 +183,48 @@ pub enum Error {
     },
     /// Draw error
     DrawError,
+    /// The hist window doesn't lie within the display
+    OutOfBounds,
 }
 
 impl Hist {
     /// Create an Hist, checking if parameters are valid
     pub fn new(upper_left: Point, size: Size) -> Hist {
-        Hist { upper_left, size }
+        Hist {
+            upper_left,
+            size,
+            bar_width: 1,
+            gap: 0,
+        }
+    }
+
+    /// Create an Hist like `new`, but returns `Error::OutOfBounds` if the window defined by
+    /// `upper_left` and `size` doesn't lie within a display of size `display_size`
+    pub fn new_checked(upper_left: Point, size: Size, display_size: Size) -> Result<Hist, Error> {
+        let fits = |start: i32, len: u32, display_len: u32| {
+            start >= 0 && start as u64 + len as u64 <= display_len as u64
+        };
+        if fits(upper_left.x, size.width, display_size.width)
+            && fits(upper_left.y, size.height, display_size.height)
+        {
+            Ok(Hist::new(upper_left, size))
+        } else {
+            Err(Error::OutOfBounds)
+        }
+    }
+
+    /// Create an Hist for a data source of size `N`, the width of the window is derived from `N`
+    /// so that drawing can't fail with `Error::RingSizeMismatch` (unless the bar width is changed)
+    pub fn for_ring<const N: usize>(upper_left: Point, height: u32) -> Hist {
+        Hist::new(upper_left, Size::new(N as u32, height))
+    }
+
+    /// Set the width of every bar and the gap between bars, by default bars are 1 pixel wide
+    /// without gap. A `bar_width` of 0 is treated as 1.
+    pub fn with_bar_width(mut self, bar_width: u32, gap: u32) -> Hist {
+        self.bar_width = bar_width.max(1);
+        self.gap = gap;
+        self
     }
 
     /// The hist window size
@@ -45,46 +232,401 @@ impl Hist {
         &self.size
     }
 
+    /// The width of every bar
+    pub fn bar_width(&self) -> u32 {
+        self.bar_width
+    }
+
+    /// The gap between bars
+    pub fn gap(&self) -> u32 {
+        self.gap
+    }
+
+    fn bar_pitch(&self) -> u32 {
+        self.bar_width + self.gap
+    }
+
     /// Draw the histogram on a display
-    pub fn draw<C: PixelColor, D: DrawTarget<Color = C>, const N: usize>(
+    pub fn draw<C: PixelColor, D: DrawTarget<Color = C>, H: HistData<N>, const N: usize>(
         &self,
-        ring: &Ring<i16, N>,
+        ring: &H,
         display: &mut D,
         foreground: C,
         background: C,
     ) -> Result<(), Error> {
         let lines = self.draw_lines(ring)?;
         for points in lines.iter() {
-            Line::new(points[0], points[1])
-                .into_styled(PrimitiveStyle::with_stroke(foreground, 1))
+            for dx in 0..self.bar_width as i32 {
+                let offset = Point::new(dx, 0);
+                Line::new(points[0] + offset, points[1] + offset)
+                    .into_styled(PrimitiveStyle::with_stroke(foreground, 1))
+                    .draw(display)
+                    .map_err(|_| Error::DrawError)?;
+                Line::new(points[1] + offset, points[2] + offset)
+                    .into_styled(PrimitiveStyle::with_stroke(background, 1))
+                    .draw(display)
+                    .map_err(|_| Error::DrawError)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw the histogram on a display using filled rectangles, so that bars wider than 1 pixel
+    /// are drawn with a single primitive
+    pub fn draw_filled<C: PixelColor, D: DrawTarget<Color = C>, H: HistData<N>, const N: usize>(
+        &self,
+        ring: &H,
+        display: &mut D,
+        fill: C,
+        background: C,
+    ) -> Result<(), Error> {
+        self.draw_bars(ring, display, |_| fill, background)
+    }
+
+    /// Draw the histogram on a display like `draw_filled`, with every bar colored along the
+    /// gradient from `low`, for the lowest values, to `high`, for the highest
+    pub fn draw_gradient<
+        C: Interpolate,
+        D: DrawTarget<Color = C>,
+        H: HistData<N>,
+        const N: usize,
+    >(
+        &self,
+        ring: &H,
+        display: &mut D,
+        low: C,
+        high: C,
+        background: C,
+    ) -> Result<(), Error> {
+        let steps = self.size.height.saturating_sub(1).max(1) as f32;
+        self.draw_bars(
+            ring,
+            display,
+            |bar_height| C::interpolate(low, high, (bar_height - 1) as f32 / steps),
+            background,
+        )
+    }
+
+    /// Draw the histogram like `draw_filled`, but only the columns whose bar height differs from
+    /// `prev`, the heights returned by the previous call, starting from all zeros on a cleared
+    /// window. Returns the heights to pass to the next call.
+    ///
+    /// Useful on displays where every pixel write is expensive, like SPI displays, when the data
+    /// changes slowly.
+    pub fn draw_incremental<
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+        H: HistData<N>,
+        const N: usize,
+    >(
+        &self,
+        ring: &H,
+        prev: &[i16; N],
+        display: &mut D,
+        foreground: C,
+        background: C,
+    ) -> Result<[i16; N], Error> {
+        let heights = self.bar_heights(ring)?;
+        for (column, (height, prev)) in heights.iter().zip(prev.iter()).enumerate() {
+            if height != prev {
+                let x = self.upper_left.x + (column as u32 * self.bar_pitch()) as i32;
+                self.draw_column(display, x, *height as u32, foreground, background)?;
+            }
+        }
+        Ok(heights)
+    }
+
+    /// Draw the histogram like `draw_filled`, but when the window is full of data and the data
+    /// range didn't change since the last call, the window is scrolled left by one bar and only
+    /// the newest bar is drawn. Otherwise, or if `display` fails to scroll, the whole histogram is
+    /// redrawn.
+    ///
+    /// It must be called once for every new element, with the same `state`.
+    pub fn draw_scrolling<
+        C: PixelColor,
+        D: DrawTarget<Color = C> + Scroll,
+        H: HistData<N>,
+        const N: usize,
+    >(
+        &self,
+        ring: &H,
+        state: &mut ScrollState,
+        display: &mut D,
+        foreground: C,
+        background: C,
+    ) -> Result<(), Error> {
+        self.check_size(ring)?;
+        let range = ring.range();
+        let newest = ring.iter().last();
+        if let (Some(current), Some(newest)) = (range, newest) {
+            let window = Rectangle::new(
+                Point::new(self.upper_left.x, self.upper_left.y + 1),
+                self.size,
+            );
+            if state.range == range
+                && ring.len() >= N
+                && display.scroll_left(&window, self.bar_pitch())
+            {
+                let desired_range = Range::new(1i16, self.size.height as i16).unwrap();
+                let height = current.rescale(newest, &desired_range) as u32;
+                let x = self.bar_x(1, 0);
+                // the scrolled in strip is `bar_pitch()` wide, its content is undefined, the gap
+                // after the bar is not covered by `draw_column`
+                Rectangle::new(
+                    Point::new(x + self.bar_width as i32, window.top_left.y),
+                    Size::new(self.gap, self.size.height),
+                )
+                .into_styled(PrimitiveStyle::with_fill(background))
                 .draw(display)
                 .map_err(|_| Error::DrawError)?;
-            Line::new(points[1], points[2])
-                .into_styled(PrimitiveStyle::with_stroke(background, 1))
+                return self.draw_column(display, x, height, foreground, background);
+            }
+        }
+        state.range = range;
+        self.draw_filled(ring, display, foreground, background)
+    }
+
+    /// Heights in pixels of the bars in every column, 0 for columns without data
+    fn bar_heights<H: HistData<N>, const N: usize>(&self, ring: &H) -> Result<[i16; N], Error> {
+        let lines = self.draw_lines(ring)?;
+        let total_elements = ring.len().min(N);
+        let mut heights = [0i16; N];
+        for [a, b, _] in lines.iter().take(total_elements) {
+            let column = (b.x - self.upper_left.x) as u32 / self.bar_pitch();
+            heights[column as usize] = (a.y - b.y) as i16;
+        }
+        Ok(heights)
+    }
+
+    /// Draw a bar `height` pixels high with its left edge at `x`, filling the rest of the column
+    /// with `background`
+    fn draw_column<C: PixelColor, D: DrawTarget<Color = C>>(
+        &self,
+        display: &mut D,
+        x: i32,
+        height: u32,
+        foreground: C,
+        background: C,
+    ) -> Result<(), Error> {
+        let height = height.min(self.size.height);
+        let top = self.upper_left.y + 1;
+        let bar_top = top + (self.size.height - height) as i32;
+        Rectangle::new(
+            Point::new(x, top),
+            Size::new(self.bar_width, self.size.height - height),
+        )
+        .into_styled(PrimitiveStyle::with_fill(background))
+        .draw(display)
+        .map_err(|_| Error::DrawError)?;
+        Rectangle::new(Point::new(x, bar_top), Size::new(self.bar_width, height))
+            .into_styled(PrimitiveStyle::with_fill(foreground))
+            .draw(display)
+            .map_err(|_| Error::DrawError)
+    }
+
+    /// Draw the bars with filled rectangles, the color of every bar is given by `fill` from its
+    /// height in pixels
+    fn draw_bars<
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+        H: HistData<N>,
+        F: Fn(u32) -> C,
+        const N: usize,
+    >(
+        &self,
+        ring: &H,
+        display: &mut D,
+        fill: F,
+        background: C,
+    ) -> Result<(), Error> {
+        let lines = self.draw_lines(ring)?;
+        for [a, b, c] in lines.iter().take(ring.len().min(N)) {
+            let bar_height = (a.y - b.y) as u32;
+            Rectangle::new(
+                Point::new(b.x, b.y + 1),
+                Size::new(self.bar_width, bar_height),
+            )
+            .into_styled(PrimitiveStyle::with_fill(fill(bar_height)))
+            .draw(display)
+            .map_err(|_| Error::DrawError)?;
+            Rectangle::new(*c, Size::new(self.bar_width, (b.y + 1 - c.y).max(0) as u32))
+                .into_styled(PrimitiveStyle::with_fill(background))
                 .draw(display)
                 .map_err(|_| Error::DrawError)?;
         }
         Ok(())
     }
 
-    /// internal testable method, returning N tuples of 3 points (A,B,C)
-    /// A->B will be foreground colored while B-C will be background colored
-    fn draw_lines<const N: usize>(&self, ring: &Ring<i16, N>) -> Result<[ThreePoints; N], Error> {
-        if ring.size() as u32 != self.size.width {
+    /// Draw the min-max envelope of the data, when the data contains more values than the window
+    /// width, every pixel column shows a vertical line spanning the values falling in it
+    pub fn draw_envelope<
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+        H: HistData<N>,
+        const N: usize,
+    >(
+        &self,
+        ring: &H,
+        display: &mut D,
+        color: C,
+    ) -> Result<(), Error> {
+        let range = match ring.range() {
+            Some(range) => range,
+            None => return Ok(()),
+        };
+        let width = self.size.width as usize;
+        let total_elements = ring.len();
+        let desired_range = Range::new(1i16, self.size.height as i16).unwrap();
+        let baseline = self.upper_left.y + self.size.height as i32;
+        let mut draw_column = |column: usize, min: i32, max: i32| {
+            let x = self.upper_left.x + column as i32;
+            Line::new(Point::new(x, baseline - min), Point::new(x, baseline - max))
+                .into_styled(PrimitiveStyle::with_stroke(color, 1))
+                .draw(display)
+                .map_err(|_| Error::DrawError)
+        };
+        let mut current: Option<(usize, i32, i32)> = None;
+        let rescaled = RescaleIterator::new(ring.iter(), range, desired_range);
+        for (i, resc) in rescaled.enumerate() {
+            let column = i * width / total_elements;
+            let resc = resc as i32;
+            current = match current {
+                Some((c, min, max)) if c == column => Some((c, min.min(resc), max.max(resc))),
+                Some((c, min, max)) => {
+                    draw_column(c, min, max)?;
+                    Some((column, resc, resc))
+                }
+                None => Some((column, resc, resc)),
+            };
+        }
+        if let Some((c, min, max)) = current {
+            draw_column(c, min, max)?;
+        }
+        Ok(())
+    }
+
+    /// Draw a vertical marker line spanning the window height over the left edge of the bar of the
+    /// element at `logical_index`, where 0 is the oldest. Nothing is drawn if `logical_index` is
+    /// out of bounds.
+    pub fn draw_cursor<C: PixelColor, D: DrawTarget<Color = C>, H: HistData<N>, const N: usize>(
+        &self,
+        ring: &H,
+        display: &mut D,
+        logical_index: usize,
+        color: C,
+    ) -> Result<(), Error> {
+        self.check_size(ring)?;
+        let total_elements = ring.len().min(N);
+        if logical_index >= total_elements {
+            return Ok(());
+        }
+        let x = self.bar_x(total_elements, logical_index);
+        let baseline = self.upper_left.y + self.size.height as i32;
+        Line::new(
+            Point::new(x, baseline - self.size.height as i32 + 1),
+            Point::new(x, baseline),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(color, 1))
+        .draw(display)
+        .map_err(|_| Error::DrawError)
+    }
+
+    /// Draw the max of the data range as text on the top left corner of the window and the min
+    /// on the bottom left corner, using a 6x10 pixels font. Nothing is drawn if there is no data.
+    pub fn draw_labels<C: PixelColor, D: DrawTarget<Color = C>, H: HistData<N>, const N: usize>(
+        &self,
+        ring: &H,
+        display: &mut D,
+        text_color: C,
+    ) -> Result<(), Error> {
+        let range = match ring.range() {
+            Some(range) => range,
+            None => return Ok(()),
+        };
+        let style = MonoTextStyle::new(&FONT_6X10, text_color);
+        let baseline = self.upper_left.y + self.size.height as i32;
+        let labels = [
+            (range.max, self.upper_left.y, Baseline::Top),
+            (range.min, baseline, Baseline::Bottom),
+        ];
+        for (value, y, text_baseline) in labels.iter() {
+            let label = LabelBuf::format(*value);
+            let position = Point::new(self.upper_left.x, *y);
+            Text::with_baseline(label.as_str(), position, style, *text_baseline)
+                .draw(display)
+                .map_err(|_| Error::DrawError)?;
+        }
+        Ok(())
+    }
+
+    /// Draw an horizontal line across the window at the height of `value`, rescaled like the data,
+    /// a `value` outside the data range is clamped to the window edges. Nothing is drawn if there
+    /// is no data.
+    pub fn draw_threshold<
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+        H: HistData<N>,
+        const N: usize,
+    >(
+        &self,
+        ring: &H,
+        display: &mut D,
+        value: i16,
+        color: C,
+    ) -> Result<(), Error> {
+        let y = match self.threshold_y(ring, value) {
+            Some(y) => y,
+            None => return Ok(()),
+        };
+        let right = self.upper_left.x + self.size.width as i32 - 1;
+        Line::new(Point::new(self.upper_left.x, y), Point::new(right, y))
+            .into_styled(PrimitiveStyle::with_stroke(color, 1))
+            .draw(display)
+            .map_err(|_| Error::DrawError)
+    }
+
+    fn threshold_y<H: HistData<N>, const N: usize>(&self, ring: &H, value: i16) -> Option<i32> {
+        let range = ring.range()?;
+        let desired_range = Range::new(1i16, self.size.height as i16).unwrap();
+        let clamped = value.max(range.min).min(range.max);
+        let baseline = self.upper_left.y + self.size.height as i32;
+        Some(baseline - range.rescale(clamped, &desired_range) as i32)
+    }
+
+    /// The x of the left edge of the bar at position `i` when there are `total_elements` bars,
+    /// the newest is on the right edge of the window
+    fn bar_x(&self, total_elements: usize, i: usize) -> i32 {
+        let pitch = self.bar_pitch() as usize;
+        (self.upper_left.x as usize + self.size.width as usize - (total_elements - i) * pitch)
+            as i32
+    }
+
+    fn check_size<H: HistData<N>, const N: usize>(&self, ring: &H) -> Result<(), Error> {
+        if ring.size() as u32 * self.bar_pitch() != self.size.width {
             return Err(Error::RingSizeMismatch {
                 width: self.size.width,
                 ring_size: ring.size(),
             });
         }
+        Ok(())
+    }
+
+    /// internal testable method, returning N tuples of 3 points (A,B,C) on the left edge of every
+    /// bar, A->B will be foreground colored while B-C will be background colored
+    fn draw_lines<H: HistData<N>, const N: usize>(
+        &self,
+        ring: &H,
+    ) -> Result<[ThreePoints; N], Error> {
+        self.check_size(ring)?;
         let mut result = [ThreePoints::default(); N];
-        let total_elements = ring.len();
+        let total_elements = ring.len().min(N);
         if total_elements > 0 {
             let range = ring.range().unwrap();
             let desired_range = Range::new(1i16, self.size.height as i16).unwrap();
             let baseline = self.upper_left.y + self.size.height as i32;
-            for (i, resc) in ring.rescaled_iter(range, desired_range).enumerate() {
-                let x = (self.upper_left.x as usize + self.size.width as usize - total_elements + i)
-                    as i32;
+            let rescaled = RescaleIterator::new(ring.iter(), range, desired_range);
+            for (i, resc) in rescaled.take(N).enumerate() {
+                let x = self.bar_x(total_elements, i);
                 let a = Point::new(x, baseline);
                 let b = Point::new(x, baseline - (resc as i32));
                 let c = Point::new(x, baseline - self.size.height as i32 + 1);
@@ -97,11 +639,15 @@ impl Hist {
 
 #[cfg(test)]
 mod test {
-    use super::{Error, Hist};
+    use super::{Error, Hist, HistData, Interpolate, Scroll, ScrollState};
     use crate::hist::ThreePoints;
-    use crate::Ring;
+    use crate::{FindRange, Range, Ring};
     use assert_matches::assert_matches;
     use embedded_graphics::geometry::{Point, Size};
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::{BinaryColor, Rgb565, RgbColor};
+    use embedded_graphics::prelude::{DrawTarget, OriginDimensions, Pixel};
+    use embedded_graphics::primitives::Rectangle;
 
     #[test]
     fn test_hist_draw() {
@@ -122,6 +668,35 @@ mod test {
         assert_matches!(hist.draw_lines(&ring), Ok(_));
     }
 
+    #[test]
+    fn test_hist_for_ring() {
+        let mut ring: Ring<i16, 7> = Ring::new();
+        let hist = Hist::for_ring::<7>(Point::new(2, 3), 5);
+        assert_eq!(hist.size(), &Size::new(7, 5));
+        assert_matches!(hist.draw_lines(&ring), Ok(_));
+        ring.append(1);
+        assert_matches!(hist.draw_lines(&ring), Ok(_));
+    }
+
+    #[test]
+    fn test_hist_new_checked() {
+        let display = Size::new(64, 32);
+        let hist = Hist::new_checked(Point::new(4, 2), Size::new(60, 30), display).unwrap();
+        assert_eq!(hist.size(), &Size::new(60, 30));
+        assert_matches!(
+            Hist::new_checked(Point::new(5, 2), Size::new(60, 30), display),
+            Err(Error::OutOfBounds)
+        );
+        assert_matches!(
+            Hist::new_checked(Point::new(4, 3), Size::new(60, 30), display),
+            Err(Error::OutOfBounds)
+        );
+        assert_matches!(
+            Hist::new_checked(Point::new(-1, 0), Size::new(10, 10), display),
+            Err(Error::OutOfBounds)
+        );
+    }
+
     #[test]
     fn test_hist() {
         let mut ring: Ring<i16, 3> = Ring::new();
@@ -153,6 +728,446 @@ mod test {
         assert_eq!(expected, hist_string);
     }
 
+    #[test]
+    fn test_hist_bar_width() {
+        let mut ring: Ring<i16, 3> = Ring::new();
+        let hist = Hist::new(Point::zero(), Size::new(3, 5)).with_bar_width(4, 0);
+        assert_eq!(hist.bar_width(), 4);
+        assert_matches!(
+            hist.draw_lines(&ring),
+            Err(Error::RingSizeMismatch {
+                width: 3,
+                ring_size: 3
+            })
+        );
+
+        let hist = Hist::new(Point::new(1, 0), Size::new(12, 5)).with_bar_width(4, 0);
+        ring.append(1);
+        let points = hist.draw_lines(&ring).unwrap();
+        assert_eq!(points[0][0].x, 9);
+        ring.append(2);
+        ring.append(3);
+        let points = hist.draw_lines(&ring).unwrap();
+        let xs: Vec<i32> = points.iter().map(|t| t[0].x).collect();
+        assert_eq!(xs, vec![1, 5, 9]);
+        for t in points.iter() {
+            assert!(t.iter().all(|p| p.x == t[0].x));
+        }
+
+        let hist = Hist::new(Point::zero(), Size::new(12, 5)).with_bar_width(3, 1);
+        let points = hist.draw_lines(&ring).unwrap();
+        let xs: Vec<i32> = points.iter().map(|t| t[0].x).collect();
+        assert_eq!(xs, vec![0, 4, 8]);
+    }
+
+    #[test]
+    fn test_hist_draw_filled() {
+        let mut ring: Ring<i16, 3> = Ring::new();
+        ring.append(1);
+        ring.append(2);
+        ring.append(3);
+        let hist = Hist::new(Point::zero(), Size::new(3, 5));
+        let mut display = MockDisplay::new();
+        hist.draw_filled(&ring, &mut display, BinaryColor::On, BinaryColor::Off)
+            .unwrap();
+        display.assert_pattern(&[
+            "   ", //
+            "..#", //
+            "..#", //
+            ".##", //
+            ".##", //
+            "###", //
+        ]);
+
+        let hist = Hist::new(Point::zero(), Size::new(6, 5)).with_bar_width(2, 0);
+        let mut display = MockDisplay::new();
+        hist.draw_filled(&ring, &mut display, BinaryColor::On, BinaryColor::Off)
+            .unwrap();
+        display.assert_pattern(&[
+            "      ", //
+            "....##", //
+            "....##", //
+            "..####", //
+            "..####", //
+            "######", //
+        ]);
+    }
+
+    #[test]
+    fn test_hist_draw_gradient() {
+        let mut ring: Ring<i16, 3> = Ring::new();
+        ring.append(10);
+        ring.append(30);
+        ring.append(20);
+        let hist = Hist::new(Point::zero(), Size::new(3, 5));
+        let mut display = MockDisplay::new();
+        hist.draw_gradient(
+            &ring,
+            &mut display,
+            Rgb565::BLUE,
+            Rgb565::RED,
+            Rgb565::BLACK,
+        )
+        .unwrap();
+        let baseline = 5;
+        assert_eq!(
+            display.get_pixel(Point::new(0, baseline)),
+            Some(Rgb565::BLUE)
+        );
+        assert_eq!(
+            display.get_pixel(Point::new(1, baseline)),
+            Some(Rgb565::RED)
+        );
+        let middle = display.get_pixel(Point::new(2, baseline)).unwrap();
+        assert_eq!(middle, Rgb565::interpolate(Rgb565::BLUE, Rgb565::RED, 0.5));
+        assert_ne!(middle, Rgb565::BLUE);
+        assert_ne!(middle, Rgb565::RED);
+        assert_eq!(display.get_pixel(Point::new(0, 1)), Some(Rgb565::BLACK));
+    }
+
+    #[test]
+    fn test_hist_draw_cursor() {
+        let mut ring: Ring<i16, 4> = Ring::new();
+        ring.append(1);
+        ring.append(2);
+        ring.append(3);
+        let hist = Hist::new(Point::new(1, 0), Size::new(8, 3)).with_bar_width(1, 1);
+        let points = hist.draw_lines(&ring).unwrap();
+        let mut display = MockDisplay::new();
+        hist.draw_cursor(&ring, &mut display, 1, BinaryColor::On)
+            .unwrap();
+        assert_eq!(points[1][0].x, 5);
+        display.assert_pattern(&[
+            "      ", //
+            "     #", //
+            "     #", //
+            "     #", //
+        ]);
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        hist.draw_cursor(&ring, &mut display, 3, BinaryColor::On)
+            .unwrap();
+        display.assert_pattern(&[]);
+        assert_matches!(
+            Hist::new(Point::zero(), Size::new(3, 3)).draw_cursor(
+                &ring,
+                &mut display,
+                0,
+                BinaryColor::On
+            ),
+            Err(Error::RingSizeMismatch { .. })
+        );
+    }
+
+    #[test]
+    fn test_hist_draw_labels() {
+        let mut ring: Ring<i16, 32> = Ring::new();
+        let hist = Hist::new(Point::new(2, 4), Size::new(32, 40));
+        let mut display = MockDisplay::<BinaryColor>::new();
+        hist.draw_labels(&ring, &mut display, BinaryColor::On)
+            .unwrap();
+        display.assert_pattern(&[]);
+
+        ring.append(-7);
+        ring.append(1234);
+        hist.draw_labels(&ring, &mut display, BinaryColor::On)
+            .unwrap();
+        let drawn_rows = |ys: core::ops::Range<i32>| {
+            ys.filter(|y| (0..64).any(|x| display.get_pixel(Point::new(x, *y)).is_some()))
+                .collect::<Vec<_>>()
+        };
+        let top = drawn_rows(0..24);
+        let bottom = drawn_rows(24..64);
+        assert!(*top.first().unwrap() >= 4 && *top.last().unwrap() < 14);
+        assert!(*bottom.first().unwrap() > 34 && *bottom.last().unwrap() <= 44);
+        let area = display.affected_area();
+        assert_eq!(area.top_left.x, 2);
+        assert!(area.size.width <= 4 * 6);
+    }
+
+    #[test]
+    fn test_hist_draw_threshold() {
+        let mut ring: Ring<i16, 4> = Ring::new();
+        let hist = Hist::new(Point::zero(), Size::new(4, 5));
+        let mut display = MockDisplay::<BinaryColor>::new();
+        hist.draw_threshold(&ring, &mut display, 3, BinaryColor::On)
+            .unwrap();
+        display.assert_pattern(&[]);
+
+        for el in [0, 8, 4, 2].iter() {
+            ring.append(*el);
+        }
+        let points = hist.draw_lines(&ring).unwrap();
+        assert_eq!(hist.threshold_y(&ring, 4), Some(points[2][1].y));
+        assert_eq!(hist.threshold_y(&ring, 100), Some(points[1][1].y));
+        assert_eq!(hist.threshold_y(&ring, -100), Some(points[0][1].y));
+
+        hist.draw_threshold(&ring, &mut display, 4, BinaryColor::On)
+            .unwrap();
+        display.assert_pattern(&[
+            "    ", //
+            "    ", //
+            "####", //
+        ]);
+    }
+
+    #[test]
+    fn test_hist_draw_incremental() {
+        let mut ring: Ring<i16, 4> = Ring::new();
+        for el in [0, 3, 2, 4].iter() {
+            ring.append(*el);
+        }
+        let hist = Hist::new(Point::zero(), Size::new(4, 4));
+        let mut display = MockDisplay::new();
+        let heights = hist
+            .draw_incremental(
+                &ring,
+                &[0; 4],
+                &mut display,
+                BinaryColor::On,
+                BinaryColor::Off,
+            )
+            .unwrap();
+        assert_eq!(heights, [1, 3, 2, 4]);
+        let mut full = MockDisplay::new();
+        hist.draw_filled(&ring, &mut full, BinaryColor::On, BinaryColor::Off)
+            .unwrap();
+        display.assert_eq(&full);
+
+        *ring.get_mut(2).unwrap() = 1;
+        let mut display = MockDisplay::new();
+        let heights = hist
+            .draw_incremental(
+                &ring,
+                &heights,
+                &mut display,
+                BinaryColor::On,
+                BinaryColor::Off,
+            )
+            .unwrap();
+        assert_eq!(heights, [1, 3, 1, 4]);
+        display.assert_pattern(&[
+            "   ", //
+            "  .", //
+            "  .", //
+            "  .", //
+            "  #", //
+        ]);
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let same = hist
+            .draw_incremental(
+                &ring,
+                &heights,
+                &mut display,
+                BinaryColor::On,
+                BinaryColor::Off,
+            )
+            .unwrap();
+        assert_eq!(same, heights);
+        display.assert_pattern(&[]);
+    }
+
+    /// a display recording the pixels drawn, which can scroll if `supported`
+    struct ScrollDisplay {
+        inner: MockDisplay<BinaryColor>,
+        supported: bool,
+        scrolls: u32,
+    }
+
+    impl OriginDimensions for ScrollDisplay {
+        fn size(&self) -> Size {
+            self.inner.size()
+        }
+    }
+
+    impl DrawTarget for ScrollDisplay {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I: IntoIterator<Item = Pixel<BinaryColor>>>(
+            &mut self,
+            pixels: I,
+        ) -> Result<(), Self::Error> {
+            self.inner.draw_iter(pixels)
+        }
+    }
+
+    impl Scroll for ScrollDisplay {
+        fn scroll_left(&mut self, _area: &Rectangle, _pixels: u32) -> bool {
+            self.scrolls += self.supported as u32;
+            self.supported
+        }
+    }
+
+    #[test]
+    fn test_hist_draw_scrolling() {
+        let mut ring: Ring<i16, 3> = Ring::new();
+        let hist = Hist::new(Point::zero(), Size::new(3, 4));
+        let mut state = ScrollState::default();
+        let mut display = ScrollDisplay {
+            inner: MockDisplay::new(),
+            supported: true,
+            scrolls: 0,
+        };
+        let mut draw = |ring: &Ring<i16, 3>, display: &mut ScrollDisplay| {
+            display.inner = MockDisplay::new();
+            hist.draw_scrolling(ring, &mut state, display, BinaryColor::On, BinaryColor::Off)
+                .unwrap();
+        };
+        ring.append(0);
+        draw(&ring, &mut display);
+        ring.append(4);
+        draw(&ring, &mut display);
+        assert_eq!(display.scrolls, 0);
+
+        ring.append(2);
+        draw(&ring, &mut display);
+        assert_eq!(display.scrolls, 1);
+        display.inner.assert_pattern(&[
+            "   ", //
+            "  .", //
+            "  .", //
+            "  #", //
+            "  #", //
+        ]);
+
+        display.supported = false;
+        ring.append(0);
+        draw(&ring, &mut display);
+        let mut full = MockDisplay::new();
+        hist.draw_filled(&ring, &mut full, BinaryColor::On, BinaryColor::Off)
+            .unwrap();
+        display.inner.assert_eq(&full);
+
+        display.supported = true;
+        ring.append(10);
+        draw(&ring, &mut display);
+        assert_eq!(display.scrolls, 1);
+        let mut full = MockDisplay::new();
+        hist.draw_filled(&ring, &mut full, BinaryColor::On, BinaryColor::Off)
+            .unwrap();
+        display.inner.assert_eq(&full);
+    }
+
+    #[test]
+    fn test_hist_draw_scrolling_gap() {
+        let mut ring: Ring<i16, 3> = Ring::new();
+        let hist = Hist::new(Point::zero(), Size::new(9, 4)).with_bar_width(2, 1);
+        let mut state = ScrollState::default();
+        let mut display = ScrollDisplay {
+            inner: MockDisplay::new(),
+            supported: true,
+            scrolls: 0,
+        };
+        for el in [0, 4].iter() {
+            ring.append(*el);
+            display.inner = MockDisplay::new();
+            hist.draw_scrolling(
+                &ring,
+                &mut state,
+                &mut display,
+                BinaryColor::On,
+                BinaryColor::Off,
+            )
+            .unwrap();
+        }
+        ring.append(2);
+        display.inner = MockDisplay::new();
+        hist.draw_scrolling(
+            &ring,
+            &mut state,
+            &mut display,
+            BinaryColor::On,
+            BinaryColor::Off,
+        )
+        .unwrap();
+        assert_eq!(display.scrolls, 1);
+        display.inner.assert_pattern(&[
+            "         ", //
+            "      ...", //
+            "      ...", //
+            "      ##.", //
+            "      ##.", //
+        ]);
+    }
+
+    #[test]
+    fn test_hist_draw_envelope() {
+        let mut ring: Ring<i16, 8> = Ring::new();
+        let hist = Hist::new(Point::zero(), Size::new(4, 4));
+        let mut display = MockDisplay::new();
+        hist.draw_envelope(&ring, &mut display, BinaryColor::On)
+            .unwrap();
+        display.assert_pattern(&[]);
+
+        for el in [0, 4, 1, 3, 2, 2, 4, 0].iter() {
+            ring.append(*el);
+        }
+        let mut display = MockDisplay::new();
+        hist.draw_envelope(&ring, &mut display, BinaryColor::On)
+            .unwrap();
+        display.assert_pattern(&[
+            "#  #", //
+            "## #", //
+            "####", //
+            "## #", //
+        ]);
+    }
+
+    /// a simple data source wrapping a slice
+    struct SliceData<'a>(&'a [i16]);
+
+    impl FindRange<i16> for SliceData<'_> {
+        fn range(&self) -> Option<Range<i16>> {
+            let min = *self.0.iter().min()?;
+            let max = *self.0.iter().max()?;
+            Range::new(min, max)
+        }
+    }
+
+    impl<const N: usize> HistData<N> for SliceData<'_> {
+        type Iter<'a>
+            = core::iter::Copied<core::slice::Iter<'a, i16>>
+        where
+            Self: 'a;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn size(&self) -> usize {
+            N
+        }
+
+        fn iter(&self) -> Self::Iter<'_> {
+            self.0.iter().copied()
+        }
+    }
+
+    #[test]
+    fn test_hist_data() {
+        let data = SliceData(&[1, 2, 3]);
+        let hist = Hist::new(Point::zero(), Size::new(3, 5));
+        let hist_string = hist_to_string::<_, 3>(&hist, &data);
+        let expected = r#"
+  #
+  #
+ ##
+ ##
+###
+"#;
+        assert_eq!(expected, hist_string);
+
+        assert_matches!(
+            hist.draw_lines::<_, 4>(&data),
+            Err(Error::RingSizeMismatch {
+                width: 3,
+                ring_size: 4
+            })
+        );
+    }
+
     /// utility to render a line of the hist at `height`
     /// `height=0` means the bottom pixel line of the hist
     fn line<const N: usize>(height: i16, points: &[ThreePoints; N]) -> [bool; N] {
@@ -165,8 +1180,8 @@ mod test {
         result
     }
 
-    fn hist_to_string<const N: usize>(hist: &Hist, ring: &Ring<i16, N>) -> String {
-        let points = hist.draw_lines(&ring).unwrap();
+    fn hist_to_string<H: HistData<N>, const N: usize>(hist: &Hist, ring: &H) -> String {
+        let points = hist.draw_lines(ring).unwrap();
         let height = hist.size().height;
         let mut result = String::new();
         result.push('\n');
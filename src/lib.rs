@@ -3,14 +3,44 @@
 
 //! A no_std append only ring buffer, when full new element replace oldest one
 
+mod atomic_ring;
 mod avg_std;
+mod bits;
+mod convert;
+#[cfg(feature = "critical-section")]
+mod cs_ring;
+#[cfg(feature = "fft")]
+mod fft;
+mod filter;
+mod reduce;
 mod rescale;
 mod ring;
+mod ring_slice;
+mod runs;
+mod text;
+mod timed;
+mod uninit_ring;
 
 #[cfg(feature = "hist")]
 pub mod hist;
 
-pub use ring::Ring;
+pub use atomic_ring::{AtomicRing, Consumer, Producer};
+pub use avg_std::{Iq, Mean};
+pub use bits::BitRing;
+pub use reduce::{MeanReducer, Reducer, SumReducer};
+pub use ring::{CapacityError, Ring};
+pub use ring_slice::RingSlice;
+pub use timed::TimedRing;
+pub use uninit_ring::UninitRing;
 
+#[cfg(feature = "critical-section")]
+pub use cs_ring::CsRing;
+
+pub use convert::Bounded;
+pub use convert::FromF64;
+pub use convert::Zero;
+
+pub use rescale::CheckedSub;
 pub use rescale::FindRange;
 pub use rescale::Range;
+pub use rescale::RescaleError;
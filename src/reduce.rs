@@ -0,0 +1,134 @@
+use crate::Ring;
+use core::ops::Add;
+
+/// A streaming aggregation over the elements of a `Ring`, see [`Ring::reduce_with`]
+pub trait Reducer<T> {
+    /// The result of the aggregation
+    type Output;
+
+    /// Resets the state, called before the first element
+    fn init(&mut self);
+
+    /// Updates the state with the next element, from the oldest
+    fn step(&mut self, el: T);
+
+    /// Returns the result computed from the state
+    fn finish(self) -> Self::Output;
+}
+
+/// Reducer computing the sum of the elements, `T::default()` if there are none
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SumReducer<T> {
+    sum: T,
+}
+
+impl<T: Copy + Default + Add<Output = T>> Reducer<T> for SumReducer<T> {
+    type Output = T;
+
+    fn init(&mut self) {
+        self.sum = T::default();
+    }
+
+    fn step(&mut self, el: T) {
+        self.sum = self.sum + el;
+    }
+
+    fn finish(self) -> T {
+        self.sum
+    }
+}
+
+/// Reducer computing the average of the elements as `f32`, `None` if there are none
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeanReducer {
+    sum: f32,
+    count: usize,
+}
+
+impl<T: Into<f32>> Reducer<T> for MeanReducer {
+    type Output = Option<f32>;
+
+    fn init(&mut self) {
+        self.sum = 0.0;
+        self.count = 0;
+    }
+
+    fn step(&mut self, el: T) {
+        self.sum += el.into();
+        self.count += 1;
+    }
+
+    fn finish(self) -> Option<f32> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum / self.count as f32)
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> Ring<T, N> {
+    /// Runs the reducer `r` over the elements starting from the oldest in one iteration, so that
+    /// custom aggregations can be plugged in
+    pub fn reduce_with<R: Reducer<T>>(&self, mut r: R) -> R::Output {
+        r.init();
+        for el in self.iter() {
+            r.step(el);
+        }
+        r.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MeanReducer, Reducer, SumReducer};
+    use crate::Ring;
+
+    /// Min, max and count computed together
+    #[derive(Default)]
+    struct MinMaxCount {
+        min_max: Option<(i16, i16)>,
+        count: usize,
+    }
+
+    impl Reducer<i16> for MinMaxCount {
+        type Output = (Option<(i16, i16)>, usize);
+
+        fn init(&mut self) {
+            self.min_max = None;
+            self.count = 0;
+        }
+
+        fn step(&mut self, el: i16) {
+            self.min_max = match self.min_max {
+                Some((min, max)) => Some((min.min(el), max.max(el))),
+                None => Some((el, el)),
+            };
+            self.count += 1;
+        }
+
+        fn finish(self) -> Self::Output {
+            (self.min_max, self.count)
+        }
+    }
+
+    #[test]
+    pub fn test_reduce_with() {
+        let mut circ: Ring<i16, 4> = Ring::new();
+        assert_eq!(circ.reduce_with(SumReducer::default()), 0);
+        assert_eq!(circ.reduce_with(MeanReducer::default()), None);
+        assert_eq!(circ.reduce_with(MinMaxCount::default()), (None, 0));
+        for el in [100, 3, -2, 7, 0].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(circ.reduce_with(SumReducer::default()), 8);
+        assert_eq!(circ.reduce_with(MeanReducer::default()), Some(2.0));
+        assert_eq!(circ.reduce_with(MinMaxCount::default()), (Some((-2, 7)), 4));
+
+        let stale = MinMaxCount {
+            min_max: Some((-100, 100)),
+            count: 10,
+        };
+        assert_eq!(circ.reduce_with(stale), (Some((-2, 7)), 4));
+    }
+}
@@ -1,9 +1,12 @@
 use crate::ring::RingIterator;
+use crate::FromF64;
 use crate::Ring;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::ops::{Add, Div, Mul, Sub};
 
 /// Contains min and max value in a `Ring`
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Range<T> {
     /// Minimum value
     pub min: T,
@@ -20,6 +23,11 @@ impl<T: PartialOrd> Range<T> {
             None
         }
     }
+
+    /// If `value` is within the range, bounds included
+    pub fn contains(&self, value: &T) -> bool {
+        &self.min <= value && value <= &self.max
+    }
 }
 
 /// Trait defining a `range` method to find min and max in one iteration
@@ -28,35 +36,182 @@ pub trait FindRange<T> {
     fn range(&self) -> Option<Range<T>>;
 }
 
-impl<T: PartialOrd + Copy + Default, const N: usize> FindRange<T> for Ring<T, N> {
+impl<T: PartialOrd + Copy, const N: usize> FindRange<T> for Ring<T, N> {
     fn range(&self) -> Option<Range<T>> {
-        if self.is_empty() {
-            return None;
+        range_of(self.iter())
+    }
+}
+
+/// Calculate min and max of the elements yielded by `iter` with one iteration
+pub(crate) fn range_of<T: PartialOrd + Copy>(
+    mut iter: impl Iterator<Item = T>,
+) -> Option<Range<T>> {
+    let first = iter.next()?;
+    let mut min_max = Range {
+        min: first,
+        max: first,
+    };
+    for el in iter {
+        if min_max.min.gt(&el) {
+            min_max.min = el;
+        }
+        if min_max.max.lt(&el) {
+            min_max.max = el;
+        }
+    }
+
+    Some(min_max)
+}
+
+impl<T: PartialOrd + Copy, const N: usize> Ring<T, N> {
+    /// Returns the range of the elements after discarding the lowest and the highest `trim`
+    /// fraction of them, useful to have a display scale not affected by outliers.
+    ///
+    /// `trim` is expected in `[0.0, 0.5)`, at least one element is always kept.
+    pub fn robust_range(&self, trim: f32) -> Option<Range<T>> {
+        let first = self.iter().next()?;
+        let len = self.len();
+        let mut sorted = [first; N];
+        for (i, el) in self.iter().enumerate() {
+            sorted[i] = el;
+        }
+        let sorted = &mut sorted[..len];
+        sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let skip = ((len as f32 * trim) as usize).min((len - 1) / 2);
+        Some(Range {
+            min: sorted[skip],
+            max: sorted[len - 1 - skip],
+        })
+    }
+
+    /// Returns the maximum element and how many elements ago it was appended, where 0 means
+    /// the newest. If the maximum appears more than once, the newest occurrence is returned.
+    pub fn max_with_age(&self) -> Option<(T, usize)> {
+        let mut result = None;
+        for (i, el) in self.iter().enumerate() {
+            match result {
+                Some((max, _)) if el < max => (),
+                _ => result = Some((el, i)),
+            }
         }
+        result.map(|(max, i)| (max, self.len() - 1 - i))
+    }
+
+    /// If every element is greater or equal than the previous one (non-strict monotonicity), so
+    /// a constant `Ring` is both increasing and decreasing. Always true with less than 2 elements.
+    pub fn is_increasing(&self) -> bool {
+        self.is_sorted_by(|prev, el| prev <= el)
+    }
+
+    /// If every element is less or equal than the previous one (non-strict monotonicity), so a
+    /// constant `Ring` is both increasing and decreasing. Always true with less than 2 elements.
+    pub fn is_decreasing(&self) -> bool {
+        self.is_sorted_by(|prev, el| prev >= el)
+    }
+
+    /// Min and max of the newest `k` elements only, the same as `range()` if `k >= len()`
+    pub fn range_of_newest(&self, k: usize) -> Option<Range<T>> {
+        range_of(self.iter().skip(self.len().saturating_sub(k)))
+    }
+
+    /// Number of elements within `range`, bounds included
+    pub fn count_in_range(&self, range: &Range<T>) -> usize {
+        self.iter().filter(|el| range.contains(el)).count()
+    }
+
+    /// Logical index of the first element after the most recent crossing of `threshold`, rising
+    /// from below to at least `threshold` or, if not `rising`, falling from at least `threshold`
+    /// to below. `None` if the signal never crossed it in that direction.
+    pub fn last_crossing(&self, threshold: T, rising: bool) -> Option<usize> {
+        let mut result = None;
         let mut iter = self.iter();
-        let first = iter.next().unwrap(); // safe because len just checked;
-        let mut min_max = Range {
-            min: first,
-            max: first,
+        let mut prev = iter.next()?;
+        for (i, el) in iter.enumerate() {
+            let crossed = if rising {
+                prev < threshold && el >= threshold
+            } else {
+                prev >= threshold && el < threshold
+            };
+            if crossed {
+                result = Some(i + 1);
+            }
+            prev = el;
+        }
+        result
+    }
+
+    /// Final state of a Schmitt trigger run over the elements from the oldest, starting low: it
+    /// goes high when an element is greater than `high`, low when an element is less than `low`
+    /// and holds otherwise, so that noise between the thresholds doesn't flip it
+    pub fn hysteresis_state(&self, low: T, high: T) -> bool {
+        self.iter().fold(false, |state, el| {
+            if el > high {
+                true
+            } else if el < low {
+                false
+            } else {
+                state
+            }
+        })
+    }
+
+    fn is_sorted_by<F: Fn(&T, &T) -> bool>(&self, f: F) -> bool {
+        let mut iter = self.iter();
+        let mut prev = match iter.next() {
+            Some(first) => first,
+            None => return true,
         };
         for el in iter {
-            if min_max.min.gt(&el) {
-                min_max.min = el;
-            }
-            if min_max.max.lt(&el) {
-                min_max.max = el;
+            if !f(&prev, &el) {
+                return false;
             }
+            prev = el;
         }
+        true
+    }
+}
 
-        Some(min_max)
+impl<T: PartialOrd + Copy + CheckedSub, const N: usize> Ring<T, N> {
+    /// Min and max of the differences between consecutive elements, `newer - older`, the max is
+    /// the steepest rise and the min the steepest fall. `None` with less than 2 elements.
+    ///
+    /// Differences overflowing `T` are skipped, with unsigned `T` these are all the falls so
+    /// the result describes only the rises, and it's `None` if the elements never rise.
+    pub fn diff_range(&self) -> Option<Range<T>> {
+        range_of(
+            self.iter()
+                .zip(self.iter().skip(1))
+                .filter_map(|(a, b)| b.checked_sub(a)),
+        )
     }
 }
 
+/// Errors returned by `try_rescaled_iter`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RescaleError {
+    /// The `current` range has `min` not strictly lower than `max`
+    InvalidCurrent,
+    /// The `desired` range has `min` not strictly lower than `max`
+    InvalidDesired,
+}
+
+/// Iterator rescaling the values of the inner iterator `I` from the `current` to the `desired`
+/// range
 #[derive(Debug)]
-pub struct RescaleIterator<'a, T, const N: usize> {
+pub struct RescaleIterator<T, I> {
     current: Range<T>,
     desired: Range<T>,
-    ring_iter: RingIterator<'a, T, N>,
+    inner: I,
+}
+
+impl<T, I: Iterator<Item = T>> RescaleIterator<T, I> {
+    pub(crate) fn new(inner: I, current: Range<T>, desired: Range<T>) -> Self {
+        RescaleIterator {
+            current,
+            desired,
+            inner,
+        }
+    }
 }
 
 impl<
@@ -72,13 +227,32 @@ impl<
     > Ring<T, N>
 {
     /// Returns an iterator over the `Ring` on which values are rescaled according to the `desired`
-    /// range
-    pub fn rescaled_iter(&self, current: Range<T>, desired: Range<T>) -> RescaleIterator<T, N> {
-        RescaleIterator {
-            current,
-            desired,
-            ring_iter: self.iter(),
+    /// range.
+    ///
+    /// Ranges can be passed by value or by reference, so that they can be reused afterwards.
+    pub fn rescaled_iter(
+        &self,
+        current: impl Borrow<Range<T>>,
+        desired: impl Borrow<Range<T>>,
+    ) -> RescaleIterator<T, RingIterator<'_, T, N>> {
+        RescaleIterator::new(self.iter(), *current.borrow(), *desired.borrow())
+    }
+
+    /// Like `rescaled_iter` but returns an error if `current` or `desired` are degenerate
+    /// (`min == max`) or reversed (`min > max`), instead of yielding meaningless values
+    pub fn try_rescaled_iter(
+        &self,
+        current: impl Borrow<Range<T>>,
+        desired: impl Borrow<Range<T>>,
+    ) -> Result<RescaleIterator<T, RingIterator<'_, T, N>>, RescaleError> {
+        let (current, desired) = (*current.borrow(), *desired.borrow());
+        if current.min.partial_cmp(&current.max) != Some(Ordering::Less) {
+            return Err(RescaleError::InvalidCurrent);
+        }
+        if desired.min.partial_cmp(&desired.max) != Some(Ordering::Less) {
+            return Err(RescaleError::InvalidDesired);
         }
+        Ok(self.rescaled_iter(current, desired))
     }
 }
 
@@ -91,34 +265,219 @@ impl<
             + Mul<Output = T>
             + Div<Output = T>
             + Into<f64>,
-        const N: usize,
-    > Iterator for RescaleIterator<'_, T, N>
+        I: Iterator<Item = T>,
+    > Iterator for RescaleIterator<T, I>
 {
     type Item = f64;
     // TODO would be nice if type returned is `T`
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.ring_iter.next().map(|el| {
-            let mut zero_one =
-                (el.into() - self.current.min.into()) / (self.current.delta().into());
-            if zero_one.is_nan() {
-                zero_one = 0.5;
-            }
-            zero_one * self.desired.delta().into() + self.desired.min.into()
-        })
+        let (current, desired) = (&self.current, &self.desired);
+        self.inner.next().map(|el| current.rescale(el, desired))
     }
 }
 
 impl<T: Sub<Output = T> + Copy> Range<T> {
-    /// Returns the range delta
+    /// Returns the range delta, it overflows if `min > max` and `T` is unsigned, see `abs_delta`
+    /// and `checked_delta`
     pub fn delta(&self) -> T {
         self.max - self.min
     }
 }
 
+impl<T: Sub<Output = T> + PartialOrd + Copy> Range<T> {
+    /// Returns the range delta as a non negative value, even if `min > max`, so that it never
+    /// overflows with unsigned `T`
+    pub fn abs_delta(&self) -> T {
+        if self.min <= self.max {
+            self.max - self.min
+        } else {
+            self.min - self.max
+        }
+    }
+}
+
+/// Subtraction returning `None` instead of overflowing
+pub trait CheckedSub: Sized {
+    /// Subtracts `rhs` from `self`, returning `None` on overflow
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_sub_int {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedSub for $t {
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_sub_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl CheckedSub for f32 {
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+}
+
+impl CheckedSub for f64 {
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+}
+
+impl<T: CheckedSub + Copy> Range<T> {
+    /// Returns the range delta, or `None` if it overflows `T`
+    pub fn checked_delta(&self) -> Option<T> {
+        self.max.checked_sub(self.min)
+    }
+}
+
+impl<T: Copy + Into<f64>> Range<T> {
+    /// Range delta computed in `f64`, so that it doesn't overflow `T`
+    fn delta_f64(&self) -> f64 {
+        self.max.into() - self.min.into()
+    }
+
+    /// Maps `value` from this range to the `desired` range, if this range is a single value the
+    /// middle of the `desired` range is returned
+    pub fn rescale(&self, value: T, desired: &Range<T>) -> f64 {
+        let desired = Range {
+            min: desired.min.into(),
+            max: desired.max.into(),
+        };
+        self.rescale_f64(value, &desired)
+    }
+
+    /// Like `rescale` but with the `desired` range in `f64`, so that it isn't limited by the
+    /// bounds of `T`, like a range of 200 rows for `i8` elements
+    pub fn rescale_f64(&self, value: T, desired: &Range<f64>) -> f64 {
+        let mut zero_one = (value.into() - self.min.into()) / self.delta_f64();
+        if zero_one.is_nan() {
+            zero_one = 0.5;
+        }
+        zero_one * desired.delta_f64() + desired.min
+    }
+}
+
+impl<T: PartialOrd + Copy + Into<f64>, const N: usize> Ring<T, N> {
+    /// Maps `value` from the range of the `Ring` to the `desired` range, returns `None` if the
+    /// `Ring` is empty
+    pub fn rescale_value(&self, value: T, desired: &Range<T>) -> Option<f32> {
+        self.range()
+            .map(|current| current.rescale(value, desired) as f32)
+    }
+}
+
+impl<T: PartialOrd + Copy + Into<f64>, const N: usize> Ring<T, N> {
+    /// Returns a new `Ring` with the elements rescaled from the range of the `Ring` to `0..=255`
+    /// and rounded, for example to drive an 8-bit DAC or a grayscale pixel. If all the elements
+    /// are equal they map to `flat`, usually the middle value 128.
+    pub fn to_u8_ring(&self, flat: u8) -> Ring<u8, N> {
+        let mut result = Ring::new();
+        if let Some(range) = self.range() {
+            let (min, max) = (range.min.into(), range.max.into());
+            for el in self.iter() {
+                let value = if max > min {
+                    ((el.into() - min) / (max - min) * 255.0 + 0.5) as u8
+                } else {
+                    flat
+                };
+                result.append(value);
+            }
+        }
+        result
+    }
+}
+
+impl<T: PartialOrd + Copy + Into<f64> + FromF64, const N: usize> Ring<T, N> {
+    /// Returns the range of the `Ring` widened to "nice" round bounds, multiple of a step of 1, 2
+    /// or 5 times a power of ten, chosen to have about `target_ticks` gridlines in the range.
+    ///
+    /// If all the elements are equal, the range is returned as is. Returns `None` if the `Ring` is
+    /// empty or the span of the range is not finite, like with infinite or `NaN` elements.
+    pub fn nice_range(&self, target_ticks: usize) -> Option<Range<T>> {
+        let range = self.range()?;
+        let (min, max) = (range.min.into(), range.max.into());
+        if min == max {
+            return Some(range);
+        }
+        if !(max - min).is_finite() {
+            return None;
+        }
+        let ticks = target_ticks.max(2) as f64;
+        let delta = nice_number(max - min, false);
+        let step = nice_number(delta / (ticks - 1.0), true);
+        Some(Range {
+            min: T::from_f64(floor(min / step) * step),
+            max: T::from_f64(-floor(-max / step) * step),
+        })
+    }
+}
+
+impl<T: Copy + Default + Into<f64> + FromF64, const N: usize> Ring<T, N> {
+    /// Returns a new `Ring` with every element rounded to the nearest multiple of `step`, halfway
+    /// values are rounded up, useful to reduce the jitter of displayed values.
+    ///
+    /// If `step` is 0 a copy of the `Ring` is returned.
+    pub fn quantized(&self, step: T) -> Ring<T, N> {
+        let step_f: f64 = step.into();
+        let mut result = Ring::new();
+        for el in self.iter() {
+            if step_f == 0.0 {
+                result.append(el);
+            } else {
+                result.append(T::from_f64(floor(el.into() / step_f + 0.5) * step_f));
+            }
+        }
+        result
+    }
+}
+
+/// Returns a number close to the positive `x` with 1, 2, 5 or 10 times a power of ten, rounded
+/// to the closest one if `round`, otherwise to the smallest which is not less than `x`
+fn nice_number(x: f64, round: bool) -> f64 {
+    let mut power = 1.0f64;
+    while power * 10.0 <= x {
+        power *= 10.0;
+    }
+    while power > x {
+        power /= 10.0;
+    }
+    let fraction = x / power;
+    let nice = if round {
+        match fraction {
+            f if f < 1.5 => 1.0,
+            f if f < 3.0 => 2.0,
+            f if f < 7.0 => 5.0,
+            _ => 10.0,
+        }
+    } else {
+        match fraction {
+            f if f <= 1.0 => 1.0,
+            f if f <= 2.0 => 2.0,
+            f if f <= 5.0 => 5.0,
+            _ => 10.0,
+        }
+    };
+    nice * power
+}
+
+fn floor(x: f64) -> f64 {
+    let truncated = x as i64 as f64;
+    if truncated > x {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{FindRange, Range, Ring};
+    use super::{FindRange, Range, RescaleError, Ring};
     const RING_SIZE: usize = 128;
 
     #[test]
@@ -155,4 +514,349 @@ mod test {
         assert_eq!(rescaled.next().map(|el| el as i16), Some(30i16));
         assert_eq!(rescaled.next(), None);
     }
+
+    #[test]
+    pub fn test_try_rescaled_iter() {
+        let mut circ: Ring<i16, RING_SIZE> = Ring::new();
+        circ.append(100i16);
+        circ.append(300);
+        let reversed = Range { min: 300, max: 100 };
+        let desired = Range { min: 20, max: 30 };
+        assert_eq!(
+            circ.try_rescaled_iter(reversed, desired).err(),
+            Some(RescaleError::InvalidCurrent)
+        );
+        let degenerate = Range { min: 20, max: 20 };
+        assert_eq!(
+            circ.try_rescaled_iter(circ.range().unwrap(), degenerate)
+                .err(),
+            Some(RescaleError::InvalidDesired)
+        );
+        let rescaled: Vec<f64> = circ
+            .try_rescaled_iter(circ.range().unwrap(), desired)
+            .unwrap()
+            .collect();
+        assert_eq!(rescaled, vec![20.0, 30.0]);
+    }
+
+    #[test]
+    pub fn test_diff_range() {
+        let mut circ: Ring<i16, 8> = Ring::new();
+        assert_eq!(circ.diff_range(), None);
+        circ.append(5);
+        assert_eq!(circ.diff_range(), None);
+        for el in [6, 8, 7, 30, 31, 29].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(circ.diff_range(), Some(Range { min: -2, max: 23 }));
+
+        let mut circ: Ring<u16, 4> = Ring::new();
+        circ.append(5);
+        circ.append(3);
+        assert_eq!(circ.diff_range(), None);
+        circ.append(4);
+        circ.append(10);
+        assert_eq!(circ.diff_range(), Some(Range { min: 1, max: 6 }));
+
+        let mut circ: Ring<i8, 4> = Ring::new();
+        for el in [-100, 100, 90].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(circ.diff_range(), Some(Range { min: -10, max: -10 }));
+    }
+
+    #[test]
+    pub fn test_to_u8_ring() {
+        let mut circ: Ring<i16, 8> = Ring::new();
+        assert!(circ.to_u8_ring(128).is_empty());
+        circ.append(-7);
+        assert_eq!(circ.to_u8_ring(128).iter().collect::<Vec<_>>(), vec![128]);
+        circ.append(-7);
+        assert_eq!(circ.to_u8_ring(0).iter().collect::<Vec<_>>(), vec![0, 0]);
+        assert_eq!(
+            circ.to_u8_ring(200).iter().collect::<Vec<_>>(),
+            vec![200, 200]
+        );
+        for el in [-100, 0, 100, -50, 50].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(
+            circ.to_u8_ring(128).iter().collect::<Vec<_>>(),
+            vec![119, 119, 0, 128, 255, 64, 191]
+        );
+
+        let mut circ: Ring<f32, 4> = Ring::new();
+        for el in [0.25, 0.5, 2.0, 0.0, 1.0].iter() {
+            circ.append(*el);
+        }
+        let scaled = circ.to_u8_ring(128);
+        assert_eq!(scaled.len(), 4);
+        assert_eq!(scaled.iter().collect::<Vec<_>>(), vec![64, 255, 0, 128]);
+    }
+
+    #[test]
+    pub fn test_quantized() {
+        let mut circ: Ring<i32, 8> = Ring::new();
+        assert!(circ.quantized(5).is_empty());
+        for el in [-13, -12, -7, -1, 0, 2, 3, 12].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(
+            circ.quantized(5).iter().collect::<Vec<_>>(),
+            vec![-15, -10, -5, 0, 0, 0, 5, 10]
+        );
+        assert_eq!(circ.quantized(0), circ);
+
+        let mut circ: Ring<f32, 4> = Ring::new();
+        for el in [1.12, -0.26, -0.24, 0.37].iter() {
+            circ.append(*el);
+        }
+        let expected = [1.0, -0.25, -0.25, 0.25];
+        for (el, exp) in circ.quantized(0.25).iter().zip(expected.iter()) {
+            assert!((el - exp).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    pub fn test_last_crossing() {
+        let mut circ: Ring<i16, 8> = Ring::new();
+        assert_eq!(circ.last_crossing(5, true), None);
+        circ.append(0);
+        assert_eq!(circ.last_crossing(5, true), None);
+        for el in [6, 2, 5, 9, 1, 0, 7, 8].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(
+            circ.iter().collect::<Vec<_>>(),
+            vec![6, 2, 5, 9, 1, 0, 7, 8]
+        );
+        assert_eq!(circ.last_crossing(5, true), Some(6));
+        assert_eq!(circ.last_crossing(5, false), Some(4));
+        assert_eq!(circ.last_crossing(9, true), Some(3));
+        assert_eq!(circ.last_crossing(9, false), Some(4));
+        assert_eq!(circ.last_crossing(10, true), None);
+        assert_eq!(circ.last_crossing(0, false), None);
+    }
+
+    #[test]
+    pub fn test_hysteresis_state() {
+        let mut circ: Ring<i16, 8> = Ring::new();
+        assert!(!circ.hysteresis_state(10, 20));
+        for el in [12, 18, 15, 19, 11].iter() {
+            circ.append(*el);
+        }
+        assert!(!circ.hysteresis_state(10, 20));
+        circ.append(21);
+        assert!(circ.hysteresis_state(10, 20));
+        for el in [11, 19, 12, 10, 18].iter() {
+            circ.append(*el);
+            assert!(circ.hysteresis_state(10, 20));
+        }
+        circ.append(9);
+        assert!(!circ.hysteresis_state(10, 20));
+        circ.append(19);
+        assert!(!circ.hysteresis_state(10, 20));
+    }
+
+    #[test]
+    pub fn test_checked_delta() {
+        let range = Range::new(i8::MIN, i8::MAX).unwrap();
+        assert_eq!(range.checked_delta(), None);
+        assert_eq!(range.max.wrapping_sub(range.min), -1);
+        assert_eq!(Range::new(-10i8, 10).unwrap().checked_delta(), Some(20));
+
+        let mut circ: Ring<i8, RING_SIZE> = Ring::new();
+        circ.append(i8::MIN);
+        circ.append(i8::MAX);
+        let current = circ.range().unwrap();
+        let desired = Range { min: 0, max: 10 };
+        let mut rescaled = circ.rescaled_iter(current, desired);
+        assert_eq!(rescaled.next(), Some(0.0));
+        assert_eq!(rescaled.next(), Some(10.0));
+        assert_eq!(rescaled.next(), None);
+    }
+
+    #[test]
+    pub fn test_abs_delta() {
+        let reversed = Range { min: 10u16, max: 3 };
+        assert_eq!(reversed.abs_delta(), 7);
+        assert_eq!(reversed.checked_delta(), None);
+        assert_eq!(Range { min: 3u16, max: 10 }.abs_delta(), 7);
+        assert_eq!(
+            Range {
+                min: 2.0f32,
+                max: -1.0
+            }
+            .abs_delta(),
+            3.0
+        );
+
+        let mut circ: Ring<u16, RING_SIZE> = Ring::new();
+        circ.append(3);
+        circ.append(10);
+        let desired = Range { min: 0u16, max: 70 };
+        let rescaled: Vec<f64> = circ.rescaled_iter(reversed, desired).collect();
+        assert_eq!(rescaled, vec![70.0, 0.0]);
+        assert_eq!(reversed.rescale(10, &desired), 0.0);
+
+        let narrow = Range::new(-100i8, 100).unwrap();
+        let rows = Range::new(0.0, 199.0).unwrap();
+        assert_eq!(narrow.rescale_f64(100, &rows), 199.0);
+        assert_eq!(narrow.rescale_f64(-100, &rows), 0.0);
+    }
+
+    #[test]
+    pub fn test_range_of_newest() {
+        let mut circ: Ring<i16, 8> = Ring::new();
+        assert_eq!(circ.range_of_newest(3), None);
+        for el in [50, -20, 4, 7, 5, 6].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(circ.range_of_newest(6), circ.range());
+        assert_eq!(circ.range_of_newest(100), circ.range());
+        assert_eq!(circ.range_of_newest(3), Some(Range { min: 5, max: 7 }));
+        assert_eq!(circ.range_of_newest(1), Some(Range { min: 6, max: 6 }));
+        assert_eq!(circ.range_of_newest(0), None);
+    }
+
+    #[test]
+    pub fn test_robust_range() {
+        let mut circ: Ring<i32, RING_SIZE> = Ring::new();
+        assert!(circ.robust_range(0.1).is_none());
+        circ.append(5);
+        let range = circ.robust_range(0.4).unwrap();
+        assert_eq!((range.min, range.max), (5, 5));
+        for i in (1..=4).chain(1000..1001).chain(6..=9) {
+            circ.append(i);
+        }
+        let range = circ.range().unwrap();
+        assert_eq!((range.min, range.max), (1, 1000));
+        let range = circ.robust_range(0.0).unwrap();
+        assert_eq!((range.min, range.max), (1, 1000));
+        let range = circ.robust_range(0.1).unwrap();
+        assert_eq!((range.min, range.max), (2, 9));
+    }
+
+    #[test]
+    pub fn test_max_with_age() {
+        let mut circ: Ring<i32, 4> = Ring::new();
+        assert_eq!(circ.max_with_age(), None);
+        circ.append(3);
+        assert_eq!(circ.max_with_age(), Some((3, 0)));
+        circ.append(1);
+        circ.append(2);
+        // oldest
+        assert_eq!(circ.max_with_age(), Some((3, 2)));
+        circ.append(0);
+        assert_eq!(circ.max_with_age(), Some((3, 3)));
+        circ.append(-1);
+        assert_eq!(circ.max_with_age(), Some((2, 2)));
+        // newest
+        circ.append(5);
+        assert_eq!(circ.max_with_age(), Some((5, 0)));
+        circ.append(5);
+        assert_eq!(circ.max_with_age(), Some((5, 0)));
+    }
+
+    #[test]
+    pub fn test_monotonic() {
+        let mut circ: Ring<i32, 4> = Ring::new();
+        assert!(circ.is_increasing() && circ.is_decreasing());
+        circ.append(1);
+        assert!(circ.is_increasing() && circ.is_decreasing());
+        for i in 2..8 {
+            circ.append(i);
+        }
+        assert!(circ.is_increasing());
+        assert!(!circ.is_decreasing());
+
+        for _ in 0..4 {
+            circ.append(3);
+        }
+        assert!(circ.is_increasing());
+        assert!(circ.is_decreasing());
+
+        for i in [1, 3, 1, 3].iter() {
+            circ.append(*i);
+        }
+        assert!(!circ.is_increasing());
+        assert!(!circ.is_decreasing());
+
+        for i in (0..4).rev() {
+            circ.append(i);
+        }
+        assert!(!circ.is_increasing());
+        assert!(circ.is_decreasing());
+    }
+
+    #[test]
+    pub fn test_count_in_range() {
+        let mut circ: Ring<i32, 8> = Ring::new();
+        let range = Range::new(0, 10).unwrap();
+        assert_eq!(circ.count_in_range(&range), 0);
+        for i in [-5, 0, 3, 10, 11, 7].iter() {
+            circ.append(*i);
+        }
+        assert_eq!(circ.count_in_range(&Range::new(-5, 11).unwrap()), 6);
+        assert_eq!(circ.count_in_range(&Range::new(20, 30).unwrap()), 0);
+        assert_eq!(circ.count_in_range(&range), 4);
+        assert_eq!(circ.count_in_range(&Range::new(3, 3).unwrap()), 1);
+    }
+
+    #[test]
+    pub fn test_rescale_borrowed_range() {
+        let mut circ: Ring<i16, RING_SIZE> = Ring::new();
+        circ.append(100i16);
+        circ.append(200);
+        circ.append(300);
+        let range = circ.range().unwrap();
+        let current: &Range<i16> = &range;
+        let desired = Range { min: 20, max: 30 };
+        let rescaled: Vec<f64> = circ.rescaled_iter(current, desired).collect();
+        assert_eq!(rescaled, vec![20.0, 25.0, 30.0]);
+
+        circ.append(400);
+        circ.clamp_all_inplace(current.min, current.max);
+        let rescaled: Vec<f64> = circ.rescaled_iter(current, desired).collect();
+        assert_eq!(rescaled, vec![20.0, 25.0, 30.0, 30.0]);
+    }
+
+    #[test]
+    pub fn test_rescale_value() {
+        let mut circ: Ring<i16, RING_SIZE> = Ring::new();
+        let desired = Range::new(20, 30).unwrap();
+        assert_eq!(circ.rescale_value(100, &desired), None);
+        circ.append(100);
+        assert_eq!(circ.rescale_value(100, &desired), Some(25.0));
+        circ.append(200);
+        circ.append(300);
+        assert_eq!(circ.rescale_value(100, &desired), Some(20.0));
+        assert_eq!(circ.rescale_value(250, &desired), Some(27.5));
+        assert_eq!(circ.rescale_value(400, &desired), Some(35.0));
+    }
+
+    #[test]
+    pub fn test_nice_range() {
+        let mut circ: Ring<i32, RING_SIZE> = Ring::new();
+        assert_eq!(circ.nice_range(5), None);
+        circ.append(42);
+        assert_eq!(circ.nice_range(5), Range::new(42, 42));
+        for el in [3, 50, 97].iter() {
+            circ.append(*el);
+        }
+        assert_eq!(circ.nice_range(5), Range::new(0, 100));
+        assert_eq!(circ.nice_range(11), Range::new(0, 100));
+
+        let mut circ: Ring<f32, RING_SIZE> = Ring::new();
+        circ.append(-0.13);
+        circ.append(0.72);
+        assert_eq!(circ.nice_range(5), Range::new(-0.2, 0.8));
+        circ.append(f32::INFINITY);
+        assert_eq!(circ.nice_range(5), None);
+        circ.clear();
+        circ.append(f32::NAN);
+        circ.append(1.0);
+        circ.append(f32::NAN);
+        assert_eq!(circ.nice_range(5), None);
+    }
 }
@@ -1,9 +1,20 @@
+use crate::Zero;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FromIterator;
+use core::iter::{Rev, Scan, StepBy};
+use core::ops::Range;
+use core::slice::IterMut;
+
 /// Append only data structure, replace oldest element when reach maximum capacity of `N` elements
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Ring<T, const N: usize> {
     data: [T; N],
     next: usize,
     len: usize,
+    total_appended: u64,
+    version: u64,
+    wrapped: bool,
 }
 
 /// Iterator over `Ring` starting from the oldest element
@@ -11,9 +22,42 @@ pub struct Ring<T, const N: usize> {
 pub struct RingIterator<'a, T, const N: usize> {
     start: usize,
     count: usize,
+    end: usize,
     circular: &'a Ring<T, N>,
 }
 
+/// Iterator over mutable references to the `Ring` elements starting from the oldest element
+#[derive(Debug)]
+pub struct RingIterMut<'a, T> {
+    first: IterMut<'a, T>,
+    second: IterMut<'a, T>,
+}
+
+/// Error returned when more than `N` elements are given to a `Ring` of size `N`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
+/// Index, in a backing storage of `capacity` slots holding `len` elements with the next one
+/// written at `next`, of the element at the `logical` position, where 0 is the oldest
+pub(crate) fn slot_index(capacity: usize, next: usize, len: usize, logical: usize) -> usize {
+    if capacity == 0 {
+        0
+    } else {
+        (next + capacity - len + logical) % capacity
+    }
+}
+
+/// Writes `el` in the `next` slot of `data`, replacing the oldest element if every slot is used,
+/// and advances `next` and `len`. Does nothing if `data` is empty.
+pub(crate) fn slot_append<T>(data: &mut [T], next: &mut usize, len: &mut usize, el: T) {
+    if data.is_empty() {
+        return;
+    }
+    data[*next] = el;
+    *len = data.len().min(*len + 1);
+    *next = (*next + 1) % data.len();
+}
+
 impl<T: Copy + Default, const N: usize> Default for Ring<T, N> {
     fn default() -> Self {
         Self::new()
@@ -21,27 +65,222 @@ impl<T: Copy + Default, const N: usize> Default for Ring<T, N> {
 }
 
 impl<T: Copy + Default, const N: usize> Ring<T, N> {
-    /// Creates a new `Ring` of give size `N`
+    /// Creates a new `Ring` of given size `N`
     pub fn new() -> Self {
         Ring {
             data: [T::default(); N],
             next: 0usize,
             len: 0usize,
+            total_appended: 0u64,
+            version: 0u64,
+            wrapped: false,
+        }
+    }
+}
+
+impl<T: Copy + Default, const N: usize> Ring<T, N> {
+    /// Creates a `Ring` with the elements of `iter`, returns `CapacityError` if `iter` yields more
+    /// than `N` elements instead of keeping only the newest like `collect`
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, CapacityError> {
+        let mut ring = Self::new();
+        for el in iter {
+            if ring.len == N {
+                return Err(CapacityError);
+            }
+            ring.append(el);
+        }
+        Ok(ring)
+    }
+
+    /// Creates a `Ring` with the newest `min(N, slice.len())` elements of `slice`, the last ones
+    pub fn from_slice_tail(slice: &[T]) -> Self {
+        let mut ring = Self::new();
+        for el in &slice[slice.len().saturating_sub(N)..] {
+            ring.append(*el);
+        }
+        ring
+    }
+}
+
+/// Collects the elements appending them, if more than `N` only the newest `N` are kept
+impl<T: Copy + Default, const N: usize> FromIterator<T> for Ring<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ring = Self::new();
+        for el in iter {
+            ring.append(el);
+        }
+        ring
+    }
+}
+
+impl<T: Copy + Zero, const N: usize> Ring<T, N> {
+    /// Creates a new empty `Ring` of given size `N` with every slot of the backing array set to
+    /// zero, unlike `new` it's a `const fn`, for example to build a `CsRing` in a `static`
+    pub const fn zeroed() -> Self {
+        Self::with_default(T::ZERO)
+    }
+}
+
+impl<T, const N: usize> Ring<T, N> {
+    /// Size in bytes of a `Ring<T, N>`, usable in const contexts to check the stack budget
+    pub const fn byte_size() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Index in `data` of the element at the `logical` position, where 0 is the oldest element
+    fn physical_index(&self, logical: usize) -> usize {
+        slot_index(N, self.next, self.len, logical)
+    }
+
+    /// Index in `data` of the oldest element
+    fn start(&self) -> usize {
+        self.physical_index(0)
+    }
+
+    /// Moves the elements at the beginning of `data`, from the oldest to the newest, without
+    /// changing the logical order
+    fn linearize(&mut self) {
+        if N == 0 {
+            return;
+        }
+        let start = self.start();
+        self.data.rotate_left(start);
+        self.next = self.len % N;
+    }
+
+    /// Ranges in `data` of the elements, the first from the oldest to the end of the array and
+    /// the second, possibly empty, from the beginning of the array to the newest
+    fn physical_ranges(&self) -> (Range<usize>, Range<usize>) {
+        let start = self.start();
+        if start + self.len <= N {
+            (start..start + self.len, 0..0)
+        } else {
+            (start..N, 0..start + self.len - N)
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Ring<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Elements<'a, T, const N: usize>(&'a Ring<T, N>);
+        impl<T: fmt::Debug, const N: usize> fmt::Debug for Elements<'_, T, N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ring = self.0;
+                f.debug_list()
+                    .entries((0..ring.len).map(|i| &ring.data[ring.physical_index(i)]))
+                    .finish()
+            }
+        }
+
+        f.debug_struct("Ring")
+            .field("elements", &Elements(self))
+            .field("len", &self.len)
+            .field("size", &N)
+            .finish()
+    }
+}
+
+/// Two rings are equal if they contain the same elements in the same order, regardless of their
+/// positions in the backing array
+impl<T: PartialEq, const N: usize> PartialEq for Ring<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && (0..self.len)
+                .all(|i| self.data[self.physical_index(i)] == other.data[other.physical_index(i)])
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for Ring<T, N> {}
+
+/// Comma separated elements from the oldest to the newest, enclosed in square brackets
+impl<T: fmt::Display, const N: usize> fmt::Display for Ring<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for i in 0..self.len {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(&self.data[self.physical_index(i)], f)?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl<T: Copy, const N: usize> Ring<T, N> {
+    /// Creates a new empty `Ring` of given size `N` with every slot of the backing array set to
+    /// `sentinel`, useful to distinguish stale slots while debugging
+    pub const fn with_default(sentinel: T) -> Self {
+        Ring {
+            data: [sentinel; N],
+            next: 0usize,
+            len: 0usize,
+            total_appended: 0u64,
+            version: 0u64,
+            wrapped: false,
         }
     }
 
-    fn increment_next(&mut self) {
-        self.next = (self.next + 1) % self.data.len()
+    /// Creates a full `Ring` where the element at logical index `i` is `f(i)`, like appending
+    /// `f(0)`, `f(1)` up to `f(N - 1)` to an empty `Ring`
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        Ring {
+            data: core::array::from_fn(f),
+            next: 0usize,
+            len: N,
+            total_appended: N as u64,
+            version: 0u64,
+            wrapped: N > 0,
+        }
     }
 
     /// Append an element to the `Ring`, if there are already `N` elements, it replaces the oldest.
     pub fn append(&mut self, el: T) {
-        self.data[self.next] = el;
-        self.len = self.data.len().min(self.len + 1);
-        self.increment_next()
+        slot_append(&mut self.data, &mut self.next, &mut self.len, el);
+        self.total_appended += 1;
+        self.version += 1;
+        self.wrapped = N > 0 && self.next == 0;
+    }
+
+    /// Appends all the elements of `src` from the oldest to the newest, as their `append` one by
+    /// one, `src` can have a different size
+    pub fn append_from<const M: usize>(&mut self, src: &Ring<T, M>) {
+        for el in src.iter() {
+            self.append(el);
+        }
+    }
+
+    /// Counter incremented by every method changing the elements, like `append` or `clear`, to
+    /// detect if results computed from the `Ring` are stale by comparing the stored value
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// If the most recent `append` filled the last slot of the backing array, so that the next
+    /// one will write again in the first slot. Useful to trigger actions once per cycle.
+    ///
+    /// Methods moving the elements in the backing array, like `make_contiguous`, don't change it.
+    pub fn just_wrapped(&self) -> bool {
+        self.wrapped
+    }
+
+    /// Number of elements ever appended to the `Ring`, it's not reset by `clear`.
+    ///
+    /// The element appended when this value was `s` has absolute sequence number `s`, so the
+    /// oldest element in the `Ring` has sequence number `total_appended() - len()`.
+    pub fn total_appended(&self) -> u64 {
+        self.total_appended
     }
 
-    /// Number of elements in the `Ring`, it never decreases.
+    /// Folds, from the oldest to the newest, only the elements with sequence number greater or
+    /// equal to `since`, usually the value of `total_appended()` when the `Ring` was last
+    /// processed.
+    pub fn fold_since<B, F: FnMut(B, T) -> B>(&self, since: u64, init: B, f: F) -> B {
+        let oldest = self.total_appended - self.len as u64;
+        let skip = since.saturating_sub(oldest).min(self.len as u64) as usize;
+        self.iter().skip(skip).fold(init, f)
+    }
+
+    /// Number of elements in the `Ring`, it decreases only on pop or `clear`.
     pub fn len(&self) -> usize {
         self.len
     }
@@ -67,43 +306,309 @@ impl<T: Copy + Default, const N: usize> Ring<T, N> {
         }
     }
 
+    /// Removes and returns the oldest element
+    pub fn pop_oldest(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let oldest = self.data[self.start()];
+        self.len -= 1;
+        self.version += 1;
+        Some(oldest)
+    }
+
+    /// Appends an element at the back, like a bounded queue, alias of `append`
+    pub fn push_back(&mut self, el: T) {
+        self.append(el)
+    }
+
+    /// Removes and returns the element at the front, the oldest, alias of `pop_oldest`
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.pop_oldest()
+    }
+
+    /// Returns the element at the front, the oldest
+    pub fn front(&self) -> Option<T> {
+        self.iter().next()
+    }
+
+    /// Returns the element at the back, the newest, alias of `last`
+    pub fn back(&self) -> Option<T> {
+        self.last()
+    }
+
+    /// Returns the element appended `n` elements ago, `ago(0)` is the newest and
+    /// `ago(len() - 1)` the oldest, `None` if `n >= len()`
+    pub fn ago(&self, n: usize) -> Option<T> {
+        if n < self.len {
+            Some(self.data[self.physical_index(self.len - 1 - n)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at logical `index`, where 0 is the oldest,
+    /// `None` if `index >= len()`
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            let physical = self.physical_index(index);
+            self.version += 1;
+            Some(&mut self.data[physical])
+        } else {
+            None
+        }
+    }
+
+    /// Swaps the elements at logical indices `a` and `b`, where 0 is the oldest
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds, `>= len()`.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.len && b < self.len,
+            "swap indices {} and {} out of bounds for len {}",
+            a,
+            b,
+            self.len
+        );
+        let (a, b) = (self.physical_index(a), self.physical_index(b));
+        self.data.swap(a, b);
+        self.version += 1;
+    }
+
+    /// Rotates the logical order so that the element at logical index `n % len()` becomes the
+    /// oldest, like `slice::rotate_left`. It's O(1) when the `Ring` is full, since only `next`
+    /// moves, otherwise the elements are moved.
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+        let n = n % self.len;
+        self.version += 1;
+        if self.len == N {
+            self.next = (self.next + n) % N;
+        } else {
+            self.linearize();
+            self.data[..self.len].rotate_left(n);
+        }
+    }
+
+    /// Rotates the logical order so that the element at logical index `len() - n % len()` becomes
+    /// the oldest, like `slice::rotate_right`, see `rotate_left`
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.len > 0 {
+            self.rotate_left(self.len - n % self.len);
+        }
+    }
+
+    /// Reverses the logical order in place, the oldest element becomes the newest
+    pub fn reverse(&mut self) {
+        self.linearize();
+        self.data[..self.len].reverse();
+        self.version += 1;
+    }
+
+    /// Fills `out` with the newest `K` elements, from the oldest to the newest of them, returns
+    /// `false` leaving `out` untouched if there are less than `K` elements
+    pub fn last_n_into<const K: usize>(&self, out: &mut [T; K]) -> bool {
+        if self.len < K {
+            return false;
+        }
+        for (slot, el) in out.iter_mut().zip(self.iter().skip(self.len - K)) {
+            *slot = el;
+        }
+        true
+    }
+
+    /// Returns the newest element and an iterator over the other elements, from the oldest
+    pub fn split_newest(&self) -> Option<(T, impl Iterator<Item = T> + '_)> {
+        let newest = self.last()?;
+        Some((newest, self.iter().take(self.len - 1)))
+    }
+
     /// Returns an iterator over the `Ring` starting from the oldest appended element
-    pub fn iter(&self) -> RingIterator<T, N> {
+    pub fn iter(&self) -> RingIterator<'_, T, N> {
         RingIterator {
             circular: self,
-            start: if self.len() == self.data.len() {
-                self.next
-            } else {
-                0
-            },
+            start: self.start(),
             count: 0usize,
+            end: self.len,
+        }
+    }
+
+    /// Returns an iterator over the `Ring` starting from the newest appended element
+    pub fn iter_rev(&self) -> Rev<RingIterator<'_, T, N>> {
+        self.iter().rev()
+    }
+
+    /// Returns the elements as a pair of slices which, concatenated, are in logical order, the
+    /// second slice is empty if the elements are contiguous in the backing array
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.physical_ranges();
+        (&self.data[first], &self.data[second])
+    }
+
+    /// Rearranges the backing array so that the elements are contiguous and in logical order,
+    /// returning them as a single slice, like `VecDeque::make_contiguous`
+    pub fn make_contiguous(&mut self) -> &[T] {
+        self.linearize();
+        &self.data[..self.len]
+    }
+
+    /// Returns an iterator over mutable references to the elements starting from the oldest, to
+    /// update them in place
+    pub fn iter_mut(&mut self) -> RingIterMut<'_, T> {
+        let (first, second) = self.physical_ranges();
+        self.version += 1;
+        let (head, tail) = self.data.split_at_mut(first.start);
+        RingIterMut {
+            first: tail[..first.len()].iter_mut(),
+            second: head[second].iter_mut(),
         }
     }
 
+    /// Returns an iterator over `(age_ms, element)` starting from the oldest, where elements are
+    /// appended every `period_ms` milliseconds and the newest has age 0
+    pub fn iter_with_age(&self, period_ms: u32) -> impl Iterator<Item = (u64, T)> + '_ {
+        let newest = self.len as u64;
+        self.iter()
+            .zip(1u64..)
+            .map(move |(el, i)| ((newest - i) * period_ms as u64, el))
+    }
+
+    /// Returns an iterator over every `step`-th element starting from the oldest, elements skipped
+    /// are not read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    pub fn iter_step_by(&self, step: usize) -> StepBy<RingIterator<'_, T, N>> {
+        self.iter().step_by(step)
+    }
+
+    /// Compares this `Ring` with `other` by the value returned by `key`, for example the average
+    /// with `|r| r.avg()`
+    pub fn cmp_by<K: PartialOrd, F: Fn(&Ring<T, N>) -> K>(
+        &self,
+        other: &Ring<T, N>,
+        key: F,
+    ) -> Option<Ordering> {
+        key(self).partial_cmp(&key(other))
+    }
+
+    /// Returns an iterator carrying the state `init` across the elements from the oldest, like
+    /// `Iterator::scan`
+    pub fn scan_iter<S, B, F: FnMut(&mut S, T) -> Option<B>>(
+        &self,
+        init: S,
+        f: F,
+    ) -> Scan<RingIterator<'_, T, N>, S, F> {
+        self.iter().scan(init, f)
+    }
+
     /// Resets the `Ring`
     pub fn clear(&mut self) {
         self.len = 0;
         self.next = 0;
+        self.wrapped = false;
+        self.version += 1;
+    }
+
+    /// Returns an independent copy of the `Ring`, to be processed later without borrowing it.
+    ///
+    /// It's a deep copy of the whole backing array of `N` elements, including the slots not yet
+    /// used.
+    pub fn snapshot(&self) -> Ring<T, N> {
+        self.clone()
+    }
+
+    /// Overwrites the content of this `Ring` with the content of `src`, counters included except
+    /// `version` which is incremented
+    pub fn copy_from(&mut self, src: &Ring<T, N>) {
+        self.data = src.data;
+        self.next = src.next;
+        self.len = src.len;
+        self.total_appended = src.total_appended;
+        self.wrapped = src.wrapped;
+        self.version += 1;
+    }
+
+    /// Returns a `heapless::Vec` with the elements of the `Ring` from the oldest to the newest
+    #[cfg(feature = "heapless")]
+    pub fn to_heapless(&self) -> heapless::Vec<T, N> {
+        self.iter().collect()
     }
 }
 
-impl<'a, T: Copy + Default, const N: usize> Iterator for RingIterator<'a, T, N> {
+impl<T: Copy + PartialOrd, const N: usize> Ring<T, N> {
+    /// Clamps every element of the `Ring` into `[lo, hi]`
+    pub fn clamp_all_inplace(&mut self, lo: T, hi: T) {
+        self.version += 1;
+        for i in 0..self.len {
+            let index = self.physical_index(i);
+            let el = &mut self.data[index];
+            if *el < lo {
+                *el = lo;
+            } else if *el > hi {
+                *el = hi;
+            }
+        }
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> Ring<T, N> {
+    /// Sorts the elements in place so that `iter()` returns them in ascending order, the sort is
+    /// not stable, see `slice::sort_unstable`
+    pub fn sort_unstable(&mut self) {
+        self.linearize();
+        self.data[..self.len].sort_unstable();
+        self.version += 1;
+    }
+}
+
+impl<'a, T: Copy, const N: usize> Iterator for RingIterator<'a, T, N> {
     type Item = T;
     fn next(&mut self) -> Option<T> {
-        let len = self.circular.len();
-        if self.count == len {
+        if self.count == self.end {
             return None;
         }
-        let current_index = (self.start + self.count) % len;
+        let current_index = (self.start + self.count) % N;
         let result = self.circular.data[current_index];
         self.count += 1;
         Some(result)
     }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.count = self.end.min(self.count.saturating_add(n));
+        self.next()
+    }
+}
+
+impl<'a, T: Copy, const N: usize> DoubleEndedIterator for RingIterator<'a, T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.count == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.circular.data[(self.start + self.end) % N])
+    }
+}
+
+impl<'a, T> Iterator for RingIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.first.next().or_else(|| self.second.next())
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::Ring;
+    use super::{CapacityError, Ring};
+    use crate::FindRange;
+    use core::cmp::Ordering;
+    use core::num::NonZeroU16;
 
     const RING_SIZE: usize = 256;
 
@@ -138,4 +643,606 @@ mod test {
         }
         assert_eq!(iter.next(), None);
     }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    pub fn test_to_heapless() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert!(circ.to_heapless().is_empty());
+        for i in 0..6 {
+            circ.append(i);
+        }
+        let vec = circ.to_heapless();
+        let expected: Vec<u32> = circ.iter().collect();
+        assert_eq!(&vec[..], &expected[..]);
+        assert_eq!(&vec[..], &[2, 3, 4, 5]);
+    }
+
+    #[test]
+    pub fn test_debug() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert_eq!(
+            format!("{:?}", circ),
+            "Ring { elements: [], len: 0, size: 4 }"
+        );
+        circ.append(7);
+        circ.append(8);
+        let debug = format!("{:?}", circ);
+        assert_eq!(debug, "Ring { elements: [7, 8], len: 2, size: 4 }");
+        assert!(!debug.contains('0'));
+        for i in 1..=5 {
+            circ.append(i);
+        }
+        assert_eq!(
+            format!("{:?}", circ),
+            "Ring { elements: [2, 3, 4, 5], len: 4, size: 4 }"
+        );
+    }
+
+    #[test]
+    pub fn test_display() {
+        let mut circ: Ring<i32, 3> = Ring::new();
+        assert_eq!(circ.to_string(), "[]");
+        circ.append(-1);
+        assert_eq!(circ.to_string(), "[-1]");
+        for i in 0..5 {
+            circ.append(i);
+        }
+        assert_eq!(circ.to_string(), "[2, 3, 4]");
+    }
+
+    #[test]
+    pub fn test_fold_since() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert_eq!(circ.fold_since(0, 0, |acc, el| acc + el), 0);
+        circ.append(1);
+        circ.append(2);
+        let marker = circ.total_appended();
+        assert_eq!(marker, 2);
+        assert_eq!(circ.fold_since(0, 0, |acc, el| acc + el), 3);
+        assert_eq!(circ.fold_since(marker, 0, |acc, el| acc + el), 0);
+        circ.append(3);
+        circ.append(4);
+        assert_eq!(circ.fold_since(marker, 0, |acc, el| acc + el), 7);
+
+        // older elements already overwritten
+        for i in 5..=10 {
+            circ.append(i);
+        }
+        assert_eq!(circ.total_appended(), 10);
+        assert_eq!(
+            circ.fold_since(marker, 0, |acc, el| acc + el),
+            7 + 8 + 9 + 10
+        );
+        assert_eq!(circ.fold_since(8, 0, |acc, el| acc + el), 9 + 10);
+        assert_eq!(circ.fold_since(10, 0, |acc, el| acc + el), 0);
+
+        circ.clear();
+        assert_eq!(circ.total_appended(), 10);
+        circ.append(11);
+        assert_eq!(circ.fold_since(10, 0, |acc, el| acc + el), 11);
+    }
+
+    #[test]
+    pub fn test_eq_and_copy_from() {
+        let mut src: Ring<u32, 4> = Ring::new();
+        let mut dst: Ring<u32, 4> = Ring::new();
+        assert_eq!(src, dst);
+        for i in 0..6 {
+            src.append(i);
+        }
+        for i in 2..6 {
+            dst.append(i);
+        }
+        // same logical content, different positions in the backing array
+        assert_eq!(src, dst);
+        dst.append(6);
+        assert_ne!(src, dst);
+
+        let mut dst: Ring<u32, 4> = Ring::new();
+        dst.append(42);
+        dst.copy_from(&src);
+        assert_eq!(src, dst);
+        assert_eq!(dst.len(), 4);
+        assert_eq!(dst.last(), Some(5));
+        dst.append(6);
+        src.append(6);
+        assert_eq!(src, dst);
+    }
+
+    #[test]
+    pub fn test_split_newest() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert!(circ.split_newest().is_none());
+        circ.append(1);
+        let (newest, rest) = circ.split_newest().unwrap();
+        assert_eq!(newest, 1);
+        assert_eq!(rest.count(), 0);
+        for i in 2..7 {
+            circ.append(i);
+        }
+        let (newest, rest) = circ.split_newest().unwrap();
+        assert_eq!(Some(newest), circ.last());
+        let rest: Vec<u32> = rest.collect();
+        assert_eq!(rest.len(), circ.len() - 1);
+        assert_eq!(rest, vec![3, 4, 5]);
+    }
+
+    #[test]
+    pub fn test_iter_step_by() {
+        let mut circ: Ring<u32, 8> = Ring::new();
+        assert_eq!(circ.iter_step_by(2).count(), 0);
+        for i in 0..11 {
+            circ.append(i);
+        }
+        let all: Vec<u32> = circ.iter_step_by(1).collect();
+        assert_eq!(all, circ.iter().collect::<Vec<_>>());
+        let even: Vec<u32> = circ.iter_step_by(2).collect();
+        assert_eq!(even, vec![3, 5, 7, 9]);
+        let by_three: Vec<u32> = circ.iter_step_by(3).collect();
+        assert_eq!(by_three, vec![3, 6, 9]);
+        let oldest: Vec<u32> = circ.iter_step_by(100).collect();
+        assert_eq!(oldest, vec![3]);
+    }
+
+    #[test]
+    pub fn test_byte_size() {
+        const SIZE: usize = Ring::<[u8; 256], 256>::byte_size();
+        assert_eq!(SIZE, core::mem::size_of::<Ring<[u8; 256], 256>>());
+        const _: () = assert!(SIZE >= 256 * 256);
+        assert_eq!(
+            Ring::<u32, 4>::byte_size(),
+            core::mem::size_of::<Ring<u32, 4>>()
+        );
+    }
+
+    #[test]
+    pub fn test_cmp_by() {
+        let mut low: Ring<u8, 4> = Ring::new();
+        let mut high: Ring<u8, 4> = Ring::new();
+        for i in 0..4 {
+            low.append(i);
+            high.append(i + 10);
+        }
+        assert_eq!(low.cmp_by(&high, |r| r.avg()), Some(Ordering::Less));
+        assert_eq!(high.cmp_by(&low, |r| r.avg()), Some(Ordering::Greater));
+        assert_eq!(low.cmp_by(&low, |r| r.avg()), Some(Ordering::Equal));
+        assert_eq!(low.cmp_by(&high, |r| r.len()), Some(Ordering::Equal));
+
+        let empty: Ring<u8, 4> = Ring::new();
+        // the average of an empty ring is NaN
+        assert_eq!(empty.cmp_by(&low, |r| r.avg()), None);
+    }
+
+    #[test]
+    pub fn test_snapshot() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        circ.append(1);
+        circ.append(2);
+        let snapshot = circ.snapshot();
+        assert_eq!(snapshot, circ);
+        for i in 3..10 {
+            circ.append(i);
+        }
+        assert_eq!(snapshot.iter().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(snapshot.len(), 2);
+        assert_ne!(snapshot, circ);
+    }
+
+    #[test]
+    pub fn test_scan_iter() {
+        let mut circ: Ring<i32, 8> = Ring::new();
+        for i in [3, 1, 4, 1, 5, 9, 2, 6, 5, 3].iter() {
+            circ.append(*i);
+        }
+        let cumulative_max: Vec<i32> = circ
+            .scan_iter(i32::MIN, |max, el| {
+                *max = (*max).max(el);
+                Some(*max)
+            })
+            .collect();
+
+        let mut expected = vec![];
+        let mut max = i32::MIN;
+        for el in circ.iter() {
+            max = max.max(el);
+            expected.push(max);
+        }
+        assert_eq!(cumulative_max, expected);
+        assert_eq!(cumulative_max, vec![4, 4, 5, 9, 9, 9, 9, 9]);
+    }
+
+    #[test]
+    pub fn test_clamp_all_inplace() {
+        let mut circ: Ring<f32, 4> = Ring::new();
+        circ.clamp_all_inplace(0.0, 1.0);
+        for el in [-3.0, 5.0, -1.0, 0.5, 2.0].iter() {
+            circ.append(*el);
+        }
+        circ.clamp_all_inplace(0.0, 1.0);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![1.0, 0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    pub fn test_zeroed_static() {
+        use std::sync::Mutex;
+        static RING: Mutex<Ring<i16, 4>> = Mutex::new(Ring::zeroed());
+        assert!(RING.lock().unwrap().is_empty());
+        for i in 0..6 {
+            RING.lock().unwrap().append(i);
+        }
+        let circ = RING.lock().unwrap();
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+        assert_eq!(Ring::<f32, 3>::zeroed(), Ring::new());
+    }
+
+    #[test]
+    pub fn test_ago() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert_eq!(circ.ago(0), None);
+        circ.append(1);
+        circ.append(2);
+        assert_eq!(circ.ago(0), circ.last());
+        assert_eq!(circ.ago(1), Some(1));
+        assert_eq!(circ.ago(2), None);
+        for i in 3..10 {
+            circ.append(i);
+            assert_eq!(circ.ago(0), circ.last());
+        }
+        assert_eq!(circ.ago(3), Some(6));
+        assert_eq!(circ.ago(4), None);
+    }
+
+    #[test]
+    pub fn test_just_wrapped() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert!(!circ.just_wrapped());
+        for cycle in 0..2 {
+            for i in 1..=4 {
+                circ.append(i);
+                assert_eq!(circ.just_wrapped(), i == 4, "cycle {} element {}", cycle, i);
+            }
+        }
+        circ.clear();
+        assert!(!circ.just_wrapped());
+
+        for i in 0..6 {
+            circ.append(i);
+        }
+        assert!(!circ.just_wrapped());
+        circ.make_contiguous();
+        assert!(!circ.just_wrapped());
+        circ.reverse();
+        circ.sort_unstable();
+        circ.rotate_left(1);
+        assert!(!circ.just_wrapped());
+        let mut wraps = 0;
+        for i in 6..10 {
+            circ.append(i);
+            wraps += circ.just_wrapped() as u32;
+        }
+        assert_eq!(wraps, 1);
+        while !circ.just_wrapped() {
+            circ.append(0);
+        }
+        circ.rotate_left(1);
+        circ.make_contiguous();
+        assert!(circ.just_wrapped());
+        circ.append(8);
+        assert!(!circ.just_wrapped());
+        let from_fn: Ring<u32, 3> = Ring::from_fn(|i| i as u32);
+        assert!(from_fn.just_wrapped());
+    }
+
+    #[test]
+    pub fn test_with_default() {
+        let mut circ: Ring<u32, 4> = Ring::with_default(0xDEAD);
+        assert!(circ.is_empty());
+        assert_eq!(circ.iter().next(), None);
+        assert_eq!(circ.data, [0xDEAD; 4]);
+        circ.append(1);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(circ.data, [1, 0xDEAD, 0xDEAD, 0xDEAD]);
+        let mut zeroed: Ring<u32, 4> = Ring::zeroed();
+        zeroed.append(1);
+        assert_eq!(circ, zeroed);
+    }
+
+    #[test]
+    pub fn test_iter_mut() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert_eq!(circ.iter_mut().next(), None);
+        circ.append(1);
+        circ.append(2);
+        circ.iter_mut().for_each(|el| *el *= 2);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![2, 4]);
+        for i in 3..8 {
+            circ.append(i);
+        }
+        circ.iter_mut().for_each(|el| *el *= 2);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![8, 10, 12, 14]);
+        circ.pop_oldest();
+        assert_eq!(circ.iter_mut().count(), 3);
+    }
+
+    #[test]
+    pub fn test_get_mut() {
+        let mut circ: Ring<u32, 5> = Ring::new();
+        assert_eq!(circ.get_mut(0), None);
+        for i in 0..8 {
+            circ.append(i);
+        }
+        *circ.get_mut(2).unwrap() = 100;
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![3, 4, 100, 6, 7]);
+        assert_eq!(circ.get_mut(5), None);
+    }
+
+    #[test]
+    pub fn test_swap() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        for i in 0..6 {
+            circ.append(i);
+        }
+        circ.swap(0, 3);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![5, 3, 4, 2]);
+        assert_eq!(circ.last(), Some(2));
+        circ.swap(1, 1);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![5, 3, 4, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_swap_out_of_bounds() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        circ.append(1);
+        circ.swap(0, 1);
+    }
+
+    #[test]
+    pub fn test_sort_unstable() {
+        let mut circ: Ring<i32, 5> = Ring::new();
+        circ.sort_unstable();
+        for el in [4, -1, 7, 3].iter() {
+            circ.append(*el);
+        }
+        circ.sort_unstable();
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![-1, 3, 4, 7]);
+        for el in [9, 0, 2, 8].iter() {
+            circ.append(*el);
+        }
+        circ.sort_unstable();
+        assert_eq!(circ.len(), 5);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![0, 2, 7, 8, 9]);
+        assert_eq!(circ.last(), Some(9));
+        circ.append(1);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![2, 7, 8, 9, 1]);
+    }
+
+    #[test]
+    pub fn test_rotate() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        circ.rotate_left(3);
+        circ.rotate_right(3);
+        for i in 0..6 {
+            circ.append(i);
+        }
+        circ.rotate_left(1);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![3, 4, 5, 2]);
+        assert_eq!(circ.len(), 4);
+        circ.rotate_right(6);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![5, 2, 3, 4]);
+        circ.rotate_left(4);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![5, 2, 3, 4]);
+
+        let mut circ: Ring<u32, 4> = Ring::new();
+        for i in 0..3 {
+            circ.append(i);
+        }
+        circ.rotate_left(4);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![1, 2, 0]);
+        assert_eq!(circ.len(), 3);
+        circ.rotate_right(1);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+        circ.append(3);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    pub fn test_reverse() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        circ.reverse();
+        assert_eq!(circ.iter_rev().next(), None);
+        for i in 0..3 {
+            circ.append(i);
+        }
+        let rev: Vec<_> = circ.iter_rev().collect();
+        assert_eq!(rev, vec![2, 1, 0]);
+        circ.reverse();
+        assert_eq!(circ.iter().collect::<Vec<_>>(), rev);
+        for i in 3..6 {
+            circ.append(i);
+        }
+        let rev: Vec<_> = circ.iter_rev().collect();
+        assert_eq!(rev, vec![5, 4, 3, 0]);
+        circ.reverse();
+        assert_eq!(circ.iter().collect::<Vec<_>>(), rev);
+        assert_eq!(circ.last(), Some(0));
+
+        let mut iter = circ.iter();
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next_back(), Some(0));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    pub fn test_from_fn() {
+        let circ: Ring<usize, 5> = Ring::from_fn(|i| i * i);
+        assert_eq!(circ.len(), 5);
+        assert_eq!(circ.total_appended(), 5);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![0, 1, 4, 9, 16]);
+        let mut appended: Ring<usize, 5> = Ring::new();
+        (0..5).for_each(|i| appended.append(i * i));
+        assert_eq!(circ, appended);
+    }
+
+    #[test]
+    pub fn test_from_iter() {
+        let circ: Ring<u32, 3> = (0..3).collect();
+        assert_eq!(Ring::try_from_iter(0..3), Ok(circ.clone()));
+        assert_eq!(Ring::<u32, 3>::try_from_iter(0..4), Err(CapacityError));
+        assert_eq!(Ring::<u32, 3>::try_from_iter(0..0).map(|r| r.len()), Ok(0));
+        let truncated: Ring<u32, 3> = (0..4).collect();
+        assert_eq!(truncated.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    pub fn test_iter_with_age() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert_eq!(circ.iter_with_age(10).next(), None);
+        for i in 0..6 {
+            circ.append(i);
+        }
+        let aged: Vec<_> = circ.iter_with_age(10).collect();
+        assert_eq!(aged, vec![(30, 2), (20, 3), (10, 4), (0, 5)]);
+    }
+
+    #[test]
+    pub fn test_make_contiguous() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert_eq!(circ.make_contiguous(), &[]);
+        for i in 0..6 {
+            circ.append(i);
+        }
+        assert_eq!(circ.as_slices(), (&[2, 3][..], &[4, 5][..]));
+        let expected: Vec<_> = circ.iter().collect();
+        assert_eq!(circ.make_contiguous(), &expected[..]);
+        assert_eq!(circ.as_slices(), (&expected[..], &[][..]));
+        circ.append(6);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+        circ.pop_oldest();
+        assert_eq!(circ.as_slices(), (&[4, 5][..], &[6][..]));
+        assert_eq!(circ.make_contiguous(), &[4, 5, 6]);
+    }
+
+    #[test]
+    pub fn test_last_n_into() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        let mut out = [0u32; 3];
+        circ.append(1);
+        circ.append(2);
+        assert!(!circ.last_n_into(&mut out));
+        assert_eq!(out, [0, 0, 0]);
+        for i in 3..7 {
+            circ.append(i);
+        }
+        assert!(circ.last_n_into(&mut out));
+        assert_eq!(out, [4, 5, 6]);
+        let mut all = [0u32; 4];
+        assert!(circ.last_n_into(&mut all));
+        assert_eq!(all, [3, 4, 5, 6]);
+        assert!(circ.last_n_into(&mut []));
+    }
+
+    #[test]
+    pub fn test_version() {
+        let mut circ: Ring<u32, 4> = Ring::new();
+        assert_eq!(circ.version(), 0);
+        circ.append(3);
+        circ.append(1);
+        let version = circ.version();
+        assert_eq!(version, 2);
+        let _ = (
+            circ.last(),
+            circ.iter().count(),
+            circ.range(),
+            circ.as_slices(),
+        );
+        let _ = circ.snapshot();
+        assert_eq!(circ.version(), version);
+        circ.sort_unstable();
+        assert!(circ.version() > version);
+        let version = circ.version();
+        circ.clear();
+        assert!(circ.version() > version);
+        let version = circ.version();
+        circ.pop_oldest();
+        assert_eq!(circ.version(), version);
+        circ.append(1);
+        circ.iter_mut().for_each(|el| *el += 1);
+        assert_eq!(circ.version(), version + 2);
+    }
+
+    #[test]
+    pub fn test_append_from() {
+        let mut dst: Ring<u32, 4> = Ring::new();
+        let mut src: Ring<u32, 3> = Ring::new();
+        dst.append_from(&src);
+        assert!(dst.is_empty());
+        dst.append(1);
+        dst.append(2);
+        for i in 10..14 {
+            src.append(i);
+        }
+        dst.append_from(&src);
+        assert_eq!(dst.iter().collect::<Vec<_>>(), vec![2, 11, 12, 13]);
+        assert_eq!(dst.total_appended(), 5);
+        let big: Ring<u32, 8> = (20..28).collect();
+        dst.append_from(&big);
+        assert_eq!(dst.iter().collect::<Vec<_>>(), vec![24, 25, 26, 27]);
+    }
+
+    #[test]
+    pub fn test_from_slice_tail() {
+        let circ: Ring<u32, 3> = Ring::from_slice_tail(&[1, 2, 3, 4, 5]);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(circ.total_appended(), 3);
+        let circ: Ring<u32, 3> = Ring::from_slice_tail(&[1, 2]);
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec![1, 2]);
+        assert!(Ring::<u32, 3>::from_slice_tail(&[]).is_empty());
+    }
+
+    #[test]
+    pub fn test_deque() {
+        let mut queue: Ring<u32, 3> = Ring::new();
+        assert_eq!(queue.pop_front(), None);
+        assert_eq!(queue.front(), None);
+        assert_eq!(queue.back(), None);
+        queue.push_back(1);
+        queue.push_back(2);
+        assert_eq!(queue.front(), Some(1));
+        assert_eq!(queue.back(), Some(2));
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.front(), Some(2));
+        assert_eq!(queue.iter().collect::<Vec<_>>(), vec![2]);
+        queue.push_back(3);
+        queue.push_back(4);
+        // wraps around the backing array
+        assert_eq!(queue.front(), Some(2));
+        assert_eq!(queue.back(), Some(4));
+        queue.push_back(5);
+        assert_eq!(queue.pop_front(), Some(3));
+        assert_eq!(queue.pop_front(), Some(4));
+        queue.push_back(6);
+        assert_eq!(queue.pop_front(), Some(5));
+        assert_eq!(queue.pop_front(), Some(6));
+        assert_eq!(queue.pop_front(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    pub fn test_range_without_default() {
+        let one = NonZeroU16::new(1).unwrap();
+        let mut circ: Ring<NonZeroU16, 4> = Ring::with_default(one);
+        assert!(circ.range().is_none());
+        for i in [5u16, 3, 9, 7].iter() {
+            circ.append(NonZeroU16::new(*i).unwrap());
+        }
+        let range = circ.range().unwrap();
+        assert_eq!(range.min.get(), 3);
+        assert_eq!(range.max.get(), 9);
+    }
 }
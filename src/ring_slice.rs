@@ -0,0 +1,159 @@
+use crate::rescale::range_of;
+use crate::ring::{slot_append, slot_index};
+use crate::{FindRange, Range};
+
+/// Append only data structure backed by a caller provided slice, replace oldest element when
+/// reach the capacity given by the slice length.
+///
+/// Useful when the storage can't live on the stack, for example when it's a `static` buffer.
+#[derive(Debug)]
+pub struct RingSlice<'a, T> {
+    data: &'a mut [T],
+    next: usize,
+    len: usize,
+}
+
+/// Iterator over `RingSlice` starting from the oldest element
+#[derive(Debug)]
+pub struct RingSliceIterator<'a, T> {
+    count: usize,
+    circular: &'a RingSlice<'a, T>,
+}
+
+impl<'a, T: Copy> RingSlice<'a, T> {
+    /// Creates a new empty `RingSlice` using `data` as storage, the capacity is `data.len()`
+    pub fn new(data: &'a mut [T]) -> Self {
+        RingSlice {
+            data,
+            next: 0usize,
+            len: 0usize,
+        }
+    }
+
+    /// Append an element to the `RingSlice`, if it's full, it replaces the oldest. Does nothing if
+    /// the backing slice is empty.
+    pub fn append(&mut self, el: T) {
+        slot_append(self.data, &mut self.next, &mut self.len, el)
+    }
+
+    /// Number of elements in the `RingSlice`, it decreases only on `clear`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// If the `RingSlice` is empty. Zero elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the max size of the ring, the length of the backing slice
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Return the element at the `logical` position, where 0 is the oldest
+    pub fn get(&self, logical: usize) -> Option<T> {
+        if logical < self.len {
+            Some(self.data[slot_index(self.data.len(), self.next, self.len, logical)])
+        } else {
+            None
+        }
+    }
+
+    /// Return the last item inserted
+    pub fn last(&self) -> Option<T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns an iterator over the `RingSlice` starting from the oldest appended element
+    pub fn iter(&self) -> RingSliceIterator<'_, T> {
+        RingSliceIterator {
+            circular: self,
+            count: 0usize,
+        }
+    }
+
+    /// Resets the `RingSlice`
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.next = 0;
+    }
+}
+
+impl<'a, T: Copy> Iterator for RingSliceIterator<'a, T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        let result = self.circular.get(self.count)?;
+        self.count += 1;
+        Some(result)
+    }
+}
+
+impl<T: PartialOrd + Copy> FindRange<T> for RingSlice<'_, T> {
+    fn range(&self) -> Option<Range<T>> {
+        range_of(self.iter())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RingSlice;
+    use crate::FindRange;
+
+    const RING_SIZE: usize = 256;
+
+    #[test]
+    pub fn test_ring_slice() {
+        let mut buffer = [0u32; RING_SIZE];
+        let mut circ = RingSlice::new(&mut buffer[..]);
+        assert_eq!(circ.size(), RING_SIZE);
+        assert_eq!(circ.last(), None);
+        assert_eq!(0, circ.len());
+        circ.append(1u32);
+        assert_eq!(circ.last(), Some(1));
+        assert_eq!(1, circ.len());
+        circ.append(2);
+        circ.append(3);
+
+        let mut iter = circ.iter();
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(3, circ.len());
+        for i in 0..1000 {
+            circ.append(i);
+            assert_eq!(circ.last(), Some(i));
+        }
+        assert_eq!(RING_SIZE, circ.len());
+
+        let mut iter = circ.iter();
+
+        for i in (1000 - RING_SIZE as u32)..1000 {
+            assert_eq!(iter.next(), Some(i));
+        }
+        assert_eq!(iter.next(), None);
+
+        let range = circ.range().unwrap();
+        assert_eq!(range.min, 1000 - RING_SIZE as u32);
+        assert_eq!(range.max, 999);
+
+        assert_eq!(circ.get(0), Some(1000 - RING_SIZE as u32));
+        assert_eq!(circ.get(RING_SIZE), None);
+
+        circ.clear();
+        assert!(circ.is_empty());
+        assert!(circ.range().is_none());
+    }
+
+    #[test]
+    pub fn test_ring_slice_empty() {
+        let mut circ: RingSlice<u32> = RingSlice::new(&mut []);
+        assert_eq!(circ.size(), 0);
+        circ.append(1);
+        assert!(circ.is_empty());
+        assert_eq!(circ.last(), None);
+        assert_eq!(circ.iter().next(), None);
+    }
+}
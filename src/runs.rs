@@ -0,0 +1,97 @@
+use crate::ring::RingIterator;
+use crate::Ring;
+use core::iter::Peekable;
+
+/// Iterator over `Ring` starting from the oldest element, yielding an element only when it's
+/// different from the previous one
+#[derive(Debug)]
+pub struct DedupIterator<'a, T, const N: usize> {
+    inner: RingIterator<'a, T, N>,
+    prev: Option<T>,
+}
+
+/// Iterator over `Ring` starting from the oldest element, yielding every element with the number
+/// of times it's consecutively repeated
+#[derive(Debug)]
+pub struct RunLengths<'a, T: Copy, const N: usize> {
+    inner: Peekable<RingIterator<'a, T, N>>,
+}
+
+impl<T: Copy + PartialEq, const N: usize> Ring<T, N> {
+    /// Returns an iterator collapsing consecutive equal elements, like `slice::dedup` but without
+    /// modifying the `Ring`
+    pub fn dedup_iter(&self) -> DedupIterator<'_, T, N> {
+        DedupIterator {
+            inner: self.iter(),
+            prev: None,
+        }
+    }
+
+    /// Returns an iterator over the run-length encoding of the `Ring`, pairs of element and
+    /// number of consecutive repetitions
+    pub fn run_lengths(&self) -> RunLengths<'_, T, N> {
+        RunLengths {
+            inner: self.iter().peekable(),
+        }
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> Iterator for DedupIterator<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        for el in &mut self.inner {
+            if self.prev != Some(el) {
+                self.prev = Some(el);
+                return Some(el);
+            }
+        }
+        None
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> Iterator for RunLengths<'_, T, N> {
+    type Item = (T, usize);
+
+    fn next(&mut self) -> Option<(T, usize)> {
+        let el = self.inner.next()?;
+        let mut count = 1;
+        while self.inner.next_if_eq(&el).is_some() {
+            count += 1;
+        }
+        Some((el, count))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Ring;
+
+    #[test]
+    pub fn test_dedup_iter() {
+        let mut circ: Ring<u8, 8> = Ring::new();
+        assert_eq!(circ.dedup_iter().count(), 0);
+        for el in [9, 9, 1, 1, 1, 2, 3, 3, 1, 1].iter() {
+            circ.append(*el);
+        }
+        let dedup: Vec<u8> = circ.dedup_iter().collect();
+        assert_eq!(dedup, vec![1, 2, 3, 1]);
+        let runs = circ
+            .iter()
+            .collect::<Vec<_>>()
+            .windows(2)
+            .fold(1, |acc, w| if w[0] != w[1] { acc + 1 } else { acc });
+        assert_eq!(dedup.len(), runs);
+    }
+
+    #[test]
+    pub fn test_run_lengths() {
+        let mut circ: Ring<char, 6> = Ring::new();
+        assert_eq!(circ.run_lengths().count(), 0);
+        for el in "zaabccc".chars() {
+            circ.append(el);
+        }
+        let runs: Vec<(char, usize)> = circ.run_lengths().collect();
+        assert_eq!(runs, vec![('a', 2), ('b', 1), ('c', 3)]);
+    }
+}
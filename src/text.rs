@@ -0,0 +1,128 @@
+use crate::{FindRange, Range, Ring};
+use core::fmt;
+
+/// Block characters of increasing height used by `sparkline_string`
+#[cfg(feature = "heapless")]
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+impl<T: Copy + PartialOrd + Into<f64>, const N: usize> Ring<T, N> {
+    /// Rescale every element from the range of the `Ring` into `0..levels` and round it, from the
+    /// oldest, only the first `len()` are meaningful
+    fn levels(&self, levels: usize) -> [usize; N] {
+        let top = levels.saturating_sub(1);
+        let desired = Range {
+            min: 0.0,
+            max: top as f64,
+        };
+        let mut result = [0usize; N];
+        if let Some(current) = self.range() {
+            for (level, el) in result.iter_mut().zip(self.iter()) {
+                let rescaled = current.rescale_f64(el, &desired);
+                *level = ((rescaled + 0.5) as usize).min(top);
+            }
+        }
+        result
+    }
+
+    /// Renders the elements as a vertical ASCII histogram of `height` lines of `#` and spaces,
+    /// one column per element, the smallest element is one `#` tall and the biggest `height`.
+    ///
+    /// The returned value implements `Display`, so it can be written without allocations.
+    pub fn bar_string(&self, height: usize) -> BarString<'_, T, N> {
+        BarString { ring: self, height }
+    }
+
+    /// Renders the elements as a sparkline, one of the block characters `▁▂▃▄▅▆▇█` per element
+    /// according to its rescaled magnitude.
+    ///
+    /// Every block character takes 3 bytes, so `M` should be at least `3 * N`, characters not
+    /// fitting in the string are dropped.
+    #[cfg(feature = "heapless")]
+    pub fn sparkline_string<const M: usize>(&self) -> heapless::String<M> {
+        let mut result = heapless::String::new();
+        for level in self.levels(BLOCKS.len()).iter().take(self.len()) {
+            if result.push(BLOCKS[*level]).is_err() {
+                break;
+            }
+        }
+        result
+    }
+}
+
+/// Vertical ASCII histogram of a `Ring`, see `Ring::bar_string`
+#[derive(Debug)]
+pub struct BarString<'a, T, const N: usize> {
+    ring: &'a Ring<T, N>,
+    height: usize,
+}
+
+impl<T: Copy + PartialOrd + Into<f64>, const N: usize> fmt::Display for BarString<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let levels = self.ring.levels(self.height);
+        let levels = &levels[..self.ring.len()];
+        for row in (0..self.height).rev() {
+            for level in levels {
+                f.write_str(if *level >= row { "#" } else { " " })?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Ring;
+
+    #[test]
+    pub fn test_bar_string() {
+        let mut circ: Ring<i16, 3> = Ring::new();
+        assert_eq!(circ.bar_string(2).to_string(), "\n\n");
+        circ.append(1);
+        circ.append(2);
+        circ.append(3);
+        let expected = r#"
+  #
+  #
+ ##
+ ##
+###
+"#;
+        assert_eq!(format!("\n{}", circ.bar_string(5)), expected);
+        assert_eq!(circ.bar_string(1).to_string(), "###\n");
+        assert_eq!(circ.bar_string(0).to_string(), "");
+
+        let mut circ: Ring<i8, 3> = Ring::new();
+        for el in [-100, 20, 100].iter() {
+            circ.append(*el);
+        }
+        let bars = circ.bar_string(200).to_string();
+        let count = |column: usize| {
+            bars.lines()
+                .filter(|line| line.as_bytes()[column] == b'#')
+                .count()
+        };
+        assert_eq!(bars.lines().count(), 200);
+        assert_eq!(count(0), 1);
+        assert_eq!(count(1), 120);
+        assert_eq!(count(2), 200);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    pub fn test_sparkline_string() {
+        let mut circ: Ring<u8, 8> = Ring::new();
+        assert_eq!(circ.sparkline_string::<24>().as_str(), "");
+        circ.append(5);
+        assert_eq!(circ.sparkline_string::<24>().as_str(), "▅");
+        for i in 0..8 {
+            circ.append(i * 10);
+        }
+        let sparkline = circ.sparkline_string::<24>();
+        assert_eq!(sparkline.as_str(), "▁▂▃▄▅▆▇█");
+        let chars: Vec<char> = sparkline.chars().collect();
+        assert!(chars.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(circ.sparkline_string::<10>().as_str(), "▁▂▃");
+    }
+}
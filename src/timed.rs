@@ -0,0 +1,301 @@
+use crate::ring::RingIterator;
+use crate::Ring;
+use core::iter::Zip;
+use core::ops::Sub;
+
+/// A `Ring` of values paired with a `Ring` of timestamps, appended together so that they never
+/// desync
+#[derive(Debug, Clone)]
+pub struct TimedRing<T, TS, const N: usize> {
+    values: Ring<T, N>,
+    timestamps: Ring<TS, N>,
+}
+
+impl<T: Copy + Default, TS: Copy + Default, const N: usize> Default for TimedRing<T, TS, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Default, TS: Copy + Default, const N: usize> TimedRing<T, TS, N> {
+    /// Creates a new `TimedRing` of given size `N`
+    pub fn new() -> Self {
+        TimedRing {
+            values: Ring::new(),
+            timestamps: Ring::new(),
+        }
+    }
+}
+
+impl<T: Copy, TS: Copy, const N: usize> TimedRing<T, TS, N> {
+    /// Append a value with its timestamp, if there are already `N` elements, it replaces the
+    /// oldest pair.
+    pub fn append(&mut self, value: T, timestamp: TS) {
+        self.values.append(value);
+        self.timestamps.append(timestamp);
+    }
+
+    /// Number of pairs in the `TimedRing`
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// If the `TimedRing` is empty. Zero elements
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Return the last pair inserted
+    pub fn last(&self) -> Option<(T, TS)> {
+        Some((self.values.last()?, self.timestamps.last()?))
+    }
+
+    /// Returns an iterator over the `(value, timestamp)` pairs starting from the oldest
+    pub fn iter(&self) -> Zip<RingIterator<'_, T, N>, RingIterator<'_, TS, N>> {
+        self.values.iter().zip(self.timestamps.iter())
+    }
+
+    /// The `Ring` of the values
+    pub fn values(&self) -> &Ring<T, N> {
+        &self.values
+    }
+
+    /// The `Ring` of the timestamps
+    pub fn timestamps(&self) -> &Ring<TS, N> {
+        &self.timestamps
+    }
+
+    /// Resets the `TimedRing`
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.timestamps.clear();
+    }
+}
+
+impl<T: Copy, TS: Copy + PartialOrd + Sub<Output = TS>, const N: usize> TimedRing<T, TS, N> {
+    /// Time window represented by the `TimedRing`, the newest timestamp minus the oldest one.
+    ///
+    /// Timestamps are expected non-decreasing, `None` if the newest is before the oldest, for
+    /// example after a tick counter wrapped around.
+    pub fn span(&self) -> Option<TS> {
+        let oldest = self.timestamps.iter().next()?;
+        let newest = self.timestamps.last()?;
+        if newest < oldest {
+            None
+        } else {
+            Some(newest - oldest)
+        }
+    }
+}
+
+impl<T: Copy + Default, TS: Copy + PartialOrd + Sub<Output = TS>, const N: usize>
+    TimedRing<T, TS, N>
+{
+    /// Resamples the values with sample-and-hold at times spaced by `period`, ending at the
+    /// newest timestamp and going back up to `M` samples or to the oldest timestamp. Every sample
+    /// is the value of the newest pair with timestamp not after the sample time.
+    ///
+    /// Timestamps are expected non-decreasing, the result starts from the oldest sample time. It's
+    /// empty if the newest timestamp is before the oldest, for example after a tick counter
+    /// wrapped around.
+    pub fn resample<const M: usize>(&self, period: TS) -> Ring<T, M> {
+        self.resample_with(period, |ago, _| self.values.ago(ago).unwrap_or_default())
+    }
+
+    /// Calls `f` at the sample times spaced by `period` described in [`TimedRing::resample`],
+    /// with the age of the newest pair not after the sample time and the time itself, and
+    /// collects the results from the oldest sample time
+    fn resample_with<X: Copy + Default, const M: usize>(
+        &self,
+        period: TS,
+        f: impl Fn(usize, TS) -> X,
+    ) -> Ring<X, M> {
+        let mut samples = [X::default(); M];
+        let mut count = 0;
+        if let (Some(oldest), Some(mut time)) =
+            (self.timestamps.iter().next(), self.timestamps.last())
+        {
+            if time < oldest {
+                return Ring::new();
+            }
+            let mut ago = 0;
+            while count < M {
+                while self.timestamps.ago(ago).is_some_and(|ts| ts > time) {
+                    ago += 1;
+                }
+                samples[M - 1 - count] = f(ago, time);
+                count += 1;
+                if time - oldest < period {
+                    break;
+                }
+                time = time - period;
+            }
+        }
+        let mut result = Ring::new();
+        for el in samples[M - count..].iter() {
+            result.append(*el);
+        }
+        result
+    }
+}
+
+impl<
+        T: Copy + Default + Into<f32>,
+        TS: Copy + PartialOrd + Sub<Output = TS> + Into<f32>,
+        const N: usize,
+    > TimedRing<T, TS, N>
+{
+    /// Resamples the values at times spaced by `period` like [`TimedRing::resample`], but
+    /// linearly interpolating between the two pairs around every sample time, for a smoother
+    /// output
+    pub fn resample_linear<const M: usize>(&self, period: TS) -> Ring<f32, M> {
+        self.resample_with(period, |ago, time| {
+            let (value, ts) = match (self.values.ago(ago), self.timestamps.ago(ago)) {
+                (Some(value), Some(ts)) => (value.into(), ts),
+                _ => return 0.0,
+            };
+            let next = ago
+                .checked_sub(1)
+                .and_then(|newer| Some((self.values.ago(newer)?, self.timestamps.ago(newer)?)));
+            match next {
+                Some((next_value, next_ts)) => {
+                    let interval: f32 = (next_ts - ts).into();
+                    if interval == 0.0 {
+                        next_value.into()
+                    } else {
+                        let elapsed: f32 = (time - ts).into();
+                        value + (next_value.into() - value) * elapsed / interval
+                    }
+                }
+                None => value,
+            }
+        })
+    }
+}
+
+impl<T: Copy, TS: Copy + PartialOrd + Sub<Output = TS> + Into<f32>, const N: usize>
+    TimedRing<T, TS, N>
+{
+    /// Average rate of the samples, number of intervals divided by the `span`, in samples per
+    /// timestamp unit. Returns `None` if the span is zero, which includes the case of less than
+    /// 2 elements.
+    pub fn rate(&self) -> Option<f32> {
+        let span: f32 = self.span()?.into();
+        if span == 0.0 {
+            None
+        } else {
+            Some((self.len() - 1) as f32 / span)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TimedRing;
+
+    #[test]
+    pub fn test_timed_ring() {
+        let mut timed: TimedRing<i16, u32, 16> = TimedRing::new();
+        assert!(timed.is_empty());
+        assert_eq!(timed.last(), None);
+        timed.append(-1, 7);
+        assert_eq!(timed.last(), Some((-1, 7)));
+        for i in 0..1000u32 {
+            timed.append(i as i16, i * 10);
+        }
+        assert_eq!(timed.len(), 16);
+        assert_eq!(timed.last(), Some((999, 9990)));
+        let mut count = 0;
+        for (value, timestamp) in timed.iter() {
+            assert_eq!(value as u32 * 10, timestamp);
+            count += 1;
+        }
+        assert_eq!(count, 16);
+        assert_eq!(timed.values().iter().next(), Some(984));
+        assert_eq!(timed.timestamps().iter().next(), Some(9840));
+        timed.clear();
+        assert_eq!(timed.iter().count(), 0);
+    }
+
+    #[test]
+    pub fn test_span() {
+        let mut timed: TimedRing<i16, u32, 4> = TimedRing::new();
+        assert_eq!(timed.span(), None);
+        timed.append(0, 100);
+        assert_eq!(timed.span(), Some(0));
+        timed.append(0, 130);
+        assert_eq!(timed.span(), Some(30));
+        for ts in [150, 210, 300].iter() {
+            timed.append(0, *ts);
+        }
+        assert_eq!(timed.span(), Some(300 - 130));
+
+        let mut timed: TimedRing<i16, u32, 4> = TimedRing::new();
+        timed.append(0, u32::MAX - 10);
+        timed.append(0, u32::MAX);
+        timed.append(0, 5);
+        assert_eq!(timed.span(), None);
+
+        let mut timed: TimedRing<i16, u16, 4> = TimedRing::new();
+        timed.append(0, u16::MAX);
+        timed.append(0, 3);
+        assert_eq!(timed.rate(), None);
+    }
+
+    #[test]
+    pub fn test_resample() {
+        let mut timed: TimedRing<i16, u32, 8> = TimedRing::new();
+        assert!(timed.resample::<4>(10).is_empty());
+        for (value, ts) in [(10, 0), (20, 3), (30, 11), (40, 12), (50, 20)].iter() {
+            timed.append(*value, *ts);
+        }
+        let resampled = timed.resample::<8>(4);
+        assert_eq!(
+            resampled.iter().collect::<Vec<_>>(),
+            vec![10, 20, 20, 40, 40, 50]
+        );
+        let resampled = timed.resample::<3>(4);
+        assert_eq!(resampled.iter().collect::<Vec<_>>(), vec![40, 40, 50]);
+        let resampled = timed.resample::<8>(7);
+        assert_eq!(resampled.iter().collect::<Vec<_>>(), vec![20, 40, 50]);
+        let resampled = timed.resample::<2>(0);
+        assert_eq!(resampled.iter().collect::<Vec<_>>(), vec![50, 50]);
+
+        let mut timed: TimedRing<i16, u16, 4> = TimedRing::new();
+        timed.append(1, u16::MAX - 3);
+        timed.append(2, 2);
+        assert!(timed.resample::<4>(1).is_empty());
+        assert!(timed.resample_linear::<4>(1).is_empty());
+    }
+
+    #[test]
+    pub fn test_resample_linear() {
+        let mut timed: TimedRing<i16, u16, 8> = TimedRing::new();
+        assert!(timed.resample_linear::<4>(10).is_empty());
+        for ts in [0u16, 3, 4, 11, 17, 20].iter() {
+            timed.append(*ts as i16 * 2 + 1, *ts);
+        }
+        let resampled = timed.resample_linear::<8>(5);
+        assert_eq!(resampled.len(), 5);
+        let expected = [1.0, 11.0, 21.0, 31.0, 41.0];
+        for (el, exp) in resampled.iter().zip(expected.iter()) {
+            assert!((el - exp).abs() < 1e-4);
+        }
+        timed.append(99, 20);
+        assert_eq!(timed.resample_linear::<1>(5).last(), Some(99.0));
+    }
+
+    #[test]
+    pub fn test_rate() {
+        let mut timed: TimedRing<i16, u16, 8> = TimedRing::new();
+        assert_eq!(timed.rate(), None);
+        timed.append(0, 100);
+        assert_eq!(timed.rate(), None);
+        timed.append(0, 100);
+        assert_eq!(timed.rate(), None);
+        for i in 0..20u16 {
+            timed.append(0, 1000 + i * 250);
+        }
+        assert_eq!(timed.rate(), Some(1.0 / 250.0));
+    }
+}
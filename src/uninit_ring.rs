@@ -0,0 +1,317 @@
+use core::fmt;
+use core::mem::MaybeUninit;
+
+/// Append only data structure like `Ring`, replace oldest element when reach maximum capacity of
+/// `N` elements, but with lazily initialized storage so that it doesn't require `T: Default` nor
+/// `T: Copy`, elements like `heapless::String` are moved in and out.
+///
+/// Slots are initialized only when elements are appended, overwritten elements are dropped, as
+/// are the remaining ones when the `UninitRing` is dropped.
+pub struct UninitRing<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    next: usize,
+    len: usize,
+}
+
+/// Iterator over `UninitRing` references starting from the oldest element
+#[derive(Debug)]
+pub struct UninitRingIterator<'a, T, const N: usize> {
+    start: usize,
+    count: usize,
+    circular: &'a UninitRing<T, N>,
+}
+
+impl<T, const N: usize> Default for UninitRing<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> UninitRing<T, N> {
+    /// Creates a new empty `UninitRing` of given size `N` without initializing any slot, so it's
+    /// a `const fn` for every `T`
+    pub const fn new() -> Self {
+        UninitRing {
+            data: [const { MaybeUninit::uninit() }; N],
+            next: 0usize,
+            len: 0usize,
+        }
+    }
+
+    /// Index in `data` of the element at the `logical` position, where 0 is the oldest element
+    fn physical_index(&self, logical: usize) -> usize {
+        (self.next + N - self.len + logical) % N
+    }
+
+    /// Append an element to the `UninitRing`, if there are already `N` elements, it replaces and
+    /// drops the oldest.
+    pub fn append(&mut self, el: T) {
+        drop(self.push(el))
+    }
+
+    /// Append an element to the `UninitRing` like `append`, but returns the evicted oldest element
+    /// instead of dropping it. With `N == 0` the element itself is returned.
+    pub fn push(&mut self, el: T) -> Option<T> {
+        if N == 0 {
+            return Some(el);
+        }
+        let slot = &mut self.data[self.next];
+        let evicted = if self.len == N {
+            // SAFETY: when full every slot is initialized, the oldest one is at `next`, it's
+            // overwritten below so it's not read again
+            Some(unsafe { slot.assume_init_read() })
+        } else {
+            self.len += 1;
+            None
+        };
+        slot.write(el);
+        self.next = (self.next + 1) % N;
+        evicted
+    }
+
+    /// Removes and returns the oldest element
+    pub fn pop_oldest(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let index = self.physical_index(0);
+        self.len -= 1;
+        // SAFETY: the slot of the oldest element is initialized, decreasing `len` excluded it
+        // from the live ones so it's not read again
+        Some(unsafe { self.data[index].assume_init_read() })
+    }
+
+    /// Number of elements in the `UninitRing`
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// If the `UninitRing` is empty. Zero elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the max size of the ring
+    pub fn size(&self) -> usize {
+        N
+    }
+
+    /// Return a reference to the element at the `logical` position, where 0 is the oldest
+    pub fn get(&self, logical: usize) -> Option<&T> {
+        if logical < self.len {
+            // SAFETY: the `len` slots starting from the oldest are initialized
+            Some(unsafe { self.data[self.physical_index(logical)].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Return the last item inserted
+    pub fn last(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns an iterator over the element references starting from the oldest
+    pub fn iter(&self) -> UninitRingIterator<'_, T, N> {
+        UninitRingIterator {
+            start: 0,
+            count: self.len,
+            circular: self,
+        }
+    }
+
+    /// Drops all the elements, making the `UninitRing` empty
+    pub fn clear(&mut self) {
+        while self.len > 0 {
+            let index = self.physical_index(0);
+            self.len -= 1;
+            // SAFETY: the slot of the oldest element is initialized, decreasing `len` before the
+            // drop excluded it from the live ones, so it's not dropped again even if dropping it
+            // panics and `clear` is called again while unwinding
+            unsafe { self.data[index].assume_init_drop() };
+        }
+        self.next = 0;
+    }
+}
+
+impl<T, const N: usize> Drop for UninitRing<T, N> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for UninitRing<T, N> {
+    fn clone(&self) -> Self {
+        let mut cloned = Self::new();
+        for el in self.iter() {
+            cloned.append(el.clone());
+        }
+        cloned
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for UninitRing<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for UninitRingIterator<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.start == self.count {
+            None
+        } else {
+            self.start += 1;
+            self.circular.get(self.start - 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::UninitRing;
+    use std::cell::Cell;
+    use std::string::{String, ToString};
+
+    /// Counts in `drops[id]` how many times the element with `id` has been dropped
+    struct DropTracker<'a> {
+        id: usize,
+        drops: &'a [Cell<u32>],
+    }
+
+    impl Drop for DropTracker<'_> {
+        fn drop(&mut self) {
+            self.drops[self.id].set(self.drops[self.id].get() + 1);
+        }
+    }
+
+    /// Like `DropTracker` but panics after counting the drop if `panics` is set
+    struct PanicTracker<'a> {
+        tracker: DropTracker<'a>,
+        panics: bool,
+    }
+
+    impl Drop for PanicTracker<'_> {
+        fn drop(&mut self) {
+            if self.panics {
+                panic!("drop of {}", self.tracker.id);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_uninit_ring() {
+        let mut circ: UninitRing<u32, 3> = UninitRing::new();
+        assert!(circ.is_empty());
+        assert_eq!(circ.last(), None);
+        circ.append(1);
+        circ.append(2);
+        assert_eq!(circ.len(), 2);
+        assert_eq!(circ.last(), Some(&2));
+        for i in 3..6 {
+            circ.append(i);
+        }
+        assert_eq!(circ.len(), 3);
+        assert_eq!(circ.size(), 3);
+        assert_eq!(circ.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(circ.get(0), Some(&3));
+        assert_eq!(circ.get(3), None);
+        assert_eq!(format!("{:?}", circ), "[3, 4, 5]");
+        circ.clear();
+        assert!(circ.is_empty());
+        assert_eq!(circ.iter().next(), None);
+    }
+
+    #[test]
+    pub fn test_uninit_ring_drop() {
+        let drops: Vec<Cell<u32>> = (0..10).map(|_| Cell::new(0)).collect();
+        {
+            let mut circ: UninitRing<DropTracker, 4> = UninitRing::new();
+            for id in 0..10 {
+                circ.append(DropTracker { id, drops: &drops });
+                let overwritten = (id + 1).saturating_sub(4);
+                for (i, d) in drops.iter().enumerate() {
+                    assert_eq!(d.get(), (i < overwritten) as u32);
+                }
+            }
+            let ids: Vec<usize> = circ.iter().map(|el| el.id).collect();
+            assert_eq!(ids, vec![6, 7, 8, 9]);
+        }
+        assert!(drops.iter().all(|d| d.get() == 1));
+
+        let drops: Vec<Cell<u32>> = (0..2).map(|_| Cell::new(0)).collect();
+        let mut circ: UninitRing<DropTracker, 4> = UninitRing::new();
+        for id in 0..2 {
+            circ.append(DropTracker { id, drops: &drops });
+        }
+        circ.clear();
+        assert!(drops.iter().all(|d| d.get() == 1));
+        drop(circ);
+        assert!(drops.iter().all(|d| d.get() == 1));
+    }
+
+    #[test]
+    pub fn test_uninit_ring_owned() {
+        let mut circ: UninitRing<String, 2> = UninitRing::new();
+        assert_eq!(circ.push("a".to_string()), None);
+        assert_eq!(circ.push("b".to_string()), None);
+        assert_eq!(circ.push("c".to_string()), Some("a".to_string()));
+        circ.append("d".to_string());
+        let cloned = circ.clone();
+        assert_eq!(circ.pop_oldest(), Some("c".to_string()));
+        assert_eq!(circ.len(), 1);
+        circ.append("e".to_string());
+        assert_eq!(circ.iter().collect::<Vec<_>>(), vec!["d", "e"]);
+        assert_eq!(cloned.iter().collect::<Vec<_>>(), vec!["c", "d"]);
+        assert_eq!(circ.pop_oldest(), Some("d".to_string()));
+        assert_eq!(circ.pop_oldest(), Some("e".to_string()));
+        assert_eq!(circ.pop_oldest(), None);
+
+        let mut empty: UninitRing<String, 0> = UninitRing::new();
+        assert_eq!(empty.push("a".to_string()), Some("a".to_string()));
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    pub fn test_uninit_ring_no_leaks() {
+        let drops: Vec<Cell<u32>> = (0..20).map(|_| Cell::new(0)).collect();
+        let mut circ: UninitRing<DropTracker, 3> = UninitRing::new();
+        for id in 0..20 {
+            let tracker = DropTracker { id, drops: &drops };
+            if id % 5 == 4 {
+                let popped = circ.pop_oldest().unwrap();
+                assert_eq!(drops[popped.id].get(), 0);
+                drop(popped);
+                circ.append(tracker);
+            } else if let Some(evicted) = circ.push(tracker) {
+                assert_eq!(drops[evicted.id].get(), 0);
+            }
+        }
+        let live: Vec<usize> = circ.iter().map(|el| el.id).collect();
+        for (id, d) in drops.iter().enumerate() {
+            assert_eq!(d.get(), !live.contains(&id) as u32);
+        }
+        drop(circ);
+        assert!(drops.iter().all(|d| d.get() == 1));
+    }
+
+    #[test]
+    pub fn test_uninit_ring_panicking_drop() {
+        let drops: Vec<Cell<u32>> = (0..6).map(|_| Cell::new(0)).collect();
+        let mut circ: UninitRing<PanicTracker, 4> = UninitRing::new();
+        for id in 0..6 {
+            let tracker = DropTracker { id, drops: &drops };
+            circ.append(PanicTracker {
+                tracker,
+                panics: id == 3,
+            });
+        }
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| circ.clear()));
+        assert!(result.is_err());
+        assert_eq!(circ.len(), 2);
+        drop(circ);
+        assert!(drops.iter().all(|d| d.get() == 1));
+    }
+}